@@ -0,0 +1,224 @@
+use std::fmt;
+
+use num_bigint::{BigInt, BigUint};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A puzzle answer. Numeric answers compare by value regardless of the
+/// variant they were produced as, so `Unsigned(5) == Signed(5)`.
+/// `Unsolved` carries why a part has no answer for its input.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    BigInt(BigInt),
+    Text(String),
+    Unsolved(String),
+}
+
+impl Answer {
+    pub fn to_bigint(&self) -> Option<BigInt> {
+        match self {
+            Answer::Unsigned(n) => Some(BigInt::from(*n)),
+            Answer::Signed(n) => Some(BigInt::from(*n)),
+            Answer::BigInt(n) => Some(n.clone()),
+            Answer::Text(_) | Answer::Unsolved(_) => None,
+        }
+    }
+
+    /// The answer, or why the part could not be solved.
+    pub fn into_result(self) -> Result<Answer, String> {
+        match self {
+            Answer::Unsolved(reason) => Err(reason),
+            answer => Ok(answer),
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        self.to_bigint().and_then(|n| u64::try_from(n).ok())
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        self.to_bigint().and_then(|n| i64::try_from(n).ok())
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Unsolved(a), Answer::Unsolved(b)) => a == b,
+            (Answer::Text(_) | Answer::Unsolved(_), _)
+            | (_, Answer::Text(_) | Answer::Unsolved(_)) => false,
+            _ => self.to_bigint() == other.to_bigint(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::BigInt(n) => write!(f, "{n}"),
+            Answer::Text(s) => f.write_str(s),
+            Answer::Unsolved(reason) => write!(f, "unsolved: {reason}"),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $target:ty, $($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )+
+    };
+}
+
+answer_from!(Unsigned, u64, u8, u16, u32, u64, usize);
+answer_from!(Signed, i64, i8, i16, i32, i64, isize);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        u64::try_from(value).map_or_else(|_| Answer::BigInt(value.into()), Answer::Unsigned)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        i64::try_from(value).map_or_else(|_| Answer::BigInt(value.into()), Answer::Signed)
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(value: BigUint) -> Self {
+        u64::try_from(&value).map_or_else(|_| Answer::BigInt(value.into()), Answer::Unsigned)
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        Answer::BigInt(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl<T: Into<Answer>, E: fmt::Display> From<Result<T, E>> for Answer {
+    fn from(value: Result<T, E>) -> Self {
+        value.map_or_else(|e| Answer::Unsolved(e.to_string()), Into::into)
+    }
+}
+
+// Big integers are written as strings, since formats like TOML and JSON
+// can't hold integers wider than 64 bits.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Unsigned(n) => serializer.serialize_u64(*n),
+            Answer::Signed(n) => serializer.serialize_i64(*n),
+            Answer::BigInt(n) => serializer.serialize_str(&n.to_string()),
+            Answer::Text(s) | Answer::Unsolved(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AnswerVisitor;
+
+        impl de::Visitor<'_> for AnswerVisitor {
+            type Value = Answer;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an integer or a string")
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Answer, E> {
+                Ok(Answer::Unsigned(v))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Answer, E> {
+                Ok(Answer::Signed(v))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Answer, E> {
+                Ok(match v.parse::<BigInt>() {
+                    Ok(n) => Answer::BigInt(n),
+                    Err(_) => Answer::Text(v.to_string()),
+                })
+            }
+        }
+
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn compares_numerically_across_variants() {
+        assert_eq!(Answer::Unsigned(42), Answer::Signed(42));
+        assert_eq!(Answer::Unsigned(42), Answer::BigInt(BigInt::from(42)));
+        assert_ne!(Answer::Signed(-42), Answer::Unsigned(42));
+        assert_ne!(Answer::Unsigned(42), Answer::from("42"));
+        assert_eq!(Some(42), Answer::Signed(42).as_u64());
+        assert_eq!(None, Answer::Signed(-1).as_u64());
+    }
+
+    #[test]
+    fn failed_results_are_unsolved() {
+        let solved: Result<u32, String> = Ok(7);
+        assert_eq!(Ok(Answer::Unsigned(7)), Answer::from(solved).into_result());
+
+        let failed: Result<u32, &str> = Err("no target");
+        let answer = Answer::from(failed);
+        assert_eq!(Answer::Unsolved("no target".to_string()), answer);
+        assert_ne!(Answer::from("no target"), answer);
+        assert_eq!("unsolved: no target", answer.to_string());
+        assert_eq!(Err("no target".to_string()), answer.into_result());
+    }
+
+    #[test]
+    fn wide_values_become_big_integers() {
+        let wide = u64::MAX as u128 + 1;
+        assert!(matches!(Answer::from(wide), Answer::BigInt(_)));
+        assert!(matches!(Answer::from(7u128), Answer::Unsigned(7)));
+        assert_eq!("18446744073709551616", Answer::from(wide).to_string());
+        assert!(matches!(Answer::from(BigUint::from(7u8)), Answer::Unsigned(7)));
+        assert_eq!(Answer::from(wide), Answer::from(BigUint::from(wide)));
+    }
+
+    #[test]
+    fn round_trips_through_serde() {
+        let answers = vec![
+            Answer::Unsigned(952408144115),
+            Answer::Signed(-3),
+            Answer::from(u64::MAX as u128 * 4),
+            Answer::from("FGHJ"),
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(
+            r#"[952408144115,-3,"73786976294838206460","FGHJ"]"#,
+            json
+        );
+        assert_eq!(answers, serde_json::from_str::<Vec<Answer>>(&json).unwrap());
+    }
+}
@@ -0,0 +1,61 @@
+use Direction::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [North, South, East, West];
+
+    /// (dx, dy) with y growing downwards, the way the puzzle grids are read.
+    pub fn delta(&self) -> (i8, i8) {
+        match self {
+            North => (0, -1),
+            South => (0, 1),
+            East => (1, 0),
+            West => (-1, 0),
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            North => South,
+            South => North,
+            East => West,
+            West => East,
+        }
+    }
+
+    /// Moves `(x, y)` one step, staying inside `0..maxx` and `0..maxy`.
+    pub fn step(&self, x: usize, y: usize, maxx: usize, maxy: usize) -> Option<(usize, usize)> {
+        let (dx, dy) = self.delta();
+        let nx = x.checked_add_signed(dx as isize)?;
+        let ny = y.checked_add_signed(dy as isize)?;
+        (nx < maxx && ny < maxy).then_some((nx, ny))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn steps_stay_in_bounds() {
+        assert_eq!(None, North.step(0, 0, 3, 3));
+        assert_eq!(None, West.step(0, 1, 3, 3));
+        assert_eq!(None, East.step(2, 1, 3, 3));
+        assert_eq!(Some((1, 2)), South.step(1, 1, 3, 3));
+    }
+
+    #[test]
+    fn opposites() {
+        for d in Direction::ALL {
+            assert_eq!(d, d.opposite().opposite());
+            assert_ne!(d, d.opposite());
+        }
+    }
+}
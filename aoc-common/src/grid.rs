@@ -0,0 +1,96 @@
+use itertools::Itertools;
+
+use crate::ParseError;
+
+pub fn parse_grid(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|l| l.chars().collect_vec()).collect_vec()
+}
+
+/// Like `parse_grid`, but rejects tiles outside `tiles` and ragged rows.
+pub fn parse_tiles(input: &str, tiles: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let expected = format!("one of {tiles:?}");
+    let mut width = None;
+
+    input
+        .lines()
+        .map(|line| {
+            if let Some((idx, ch)) = line.char_indices().find(|(_, ch)| !tiles.contains(*ch)) {
+                let tile = &line[idx..idx + ch.len_utf8()];
+                return Err(ParseError::at(input, tile, expected.as_str()));
+            }
+
+            let len = line.chars().count();
+            match width {
+                Some(w) if w != len => {
+                    // Point at the first extra tile, or at the end of a short row.
+                    let end = line
+                        .char_indices()
+                        .nth(w)
+                        .map_or(line.len(), |(idx, _)| idx);
+                    Err(ParseError::at(
+                        input,
+                        &line[end..],
+                        format!("a row of {w} tiles"),
+                    ))
+                }
+                _ => {
+                    width = Some(len);
+                    Ok(line.chars().collect_vec())
+                }
+            }
+        })
+        .collect()
+}
+
+pub fn grid_to_string(grid: &[Vec<char>]) -> String {
+    grid.iter().map(|l| l.iter().join("")).join("\n")
+}
+
+pub fn print_map(map: &[Vec<char>]) {
+    for l in map {
+        println!("{:?}", l);
+    }
+}
+
+pub fn transpose<T: Copy>(input: &[Vec<T>]) -> Vec<Vec<T>> {
+    assert!(!input.is_empty());
+    let xmax = input[0].len();
+    let ymax = input.len();
+
+    (0..xmax).fold(vec![], |mut acc, x| {
+        let new_row = (0..ymax).map(|y| input[y][x]).collect_vec();
+        acc.push(new_row);
+        acc
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trips_grid() {
+        let sample = "#.#\n..#";
+        assert_eq!(sample, grid_to_string(&parse_grid(sample)));
+    }
+
+    #[test]
+    fn transposes_rows_into_columns() {
+        let grid = parse_grid("ab\ncd\nef");
+        assert_eq!("ace\nbdf", grid_to_string(&transpose(&grid)));
+    }
+
+    #[test]
+    fn rejects_unknown_tiles_and_ragged_rows() {
+        assert_eq!(Ok(parse_grid("#.\n.#")), parse_tiles("#.\n.#", ".#"));
+
+        let err = parse_tiles("#.\n.x", ".#").unwrap_err();
+        assert_eq!((2, 2, "x"), (err.line, err.column, err.snippet.as_str()));
+
+        let err = parse_tiles("#.\n.#.", ".#").unwrap_err();
+        assert_eq!(
+            "line 2, column 3: expected a row of 2 tiles, found \".\"",
+            err.to_string()
+        );
+    }
+}
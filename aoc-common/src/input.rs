@@ -0,0 +1,133 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process,
+};
+
+use clap::Parser;
+
+pub const INPUTS_VAR: &str = "AOC_INPUTS";
+pub const YEAR: u16 = 2023;
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Read(PathBuf, io::Error),
+    Stdin(io::Error),
+    NoInputsDir(u8),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(f, "input file {} does not exist", path.display()),
+            InputError::Read(path, e) => write!(f, "unable to read {}: {e}", path.display()),
+            InputError::Stdin(e) => write!(f, "unable to read input from stdin: {e}"),
+            InputError::NoInputsDir(day) => write!(
+                f,
+                "no input for day {day}: pass --input <path> (or - for stdin), \
+                 or set {INPUTS_VAR} to a directory containing {}",
+                default_name(*day).display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Arguments shared by the per-day binaries.
+#[derive(Parser, Debug)]
+pub struct InputArgs {
+    /// Puzzle input file, `-` reads stdin. Defaults to $AOC_INPUTS/2023/day-XX.txt.
+    #[arg(long, short)]
+    pub input: Option<PathBuf>,
+}
+
+impl InputArgs {
+    pub fn load(&self, day: u8) -> Result<String, InputError> {
+        load(day, self.input.as_deref())
+    }
+}
+
+/// Parses the process arguments and loads the input for `day`, exiting with
+/// the error message if it can't be found.
+pub fn from_args(day: u8) -> String {
+    InputArgs::parse()
+        .load(day)
+        .unwrap_or_else(|e| exit_with_error(e))
+}
+
+/// Prints `error` and exits, for the per-day binaries.
+pub fn exit_with_error(error: impl fmt::Display) -> ! {
+    eprintln!("error: {error}");
+    process::exit(1)
+}
+
+pub fn load(day: u8, path: Option<&Path>) -> Result<String, InputError> {
+    match path {
+        Some(path) if path.as_os_str() == "-" => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(InputError::Stdin)?;
+            Ok(input)
+        }
+        Some(path) => read(path),
+        None => match env::var_os(INPUTS_VAR) {
+            Some(dir) => read(&default_path(Path::new(&dir), day)),
+            None => Err(InputError::NoInputsDir(day)),
+        },
+    }
+}
+
+pub fn default_path(inputs_dir: &Path, day: u8) -> PathBuf {
+    inputs_dir.join(default_name(day))
+}
+
+fn default_name(day: u8) -> PathBuf {
+    PathBuf::from(YEAR.to_string()).join(format!("day-{day:02}.txt"))
+}
+
+fn read(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound(path.to_path_buf()),
+        _ => InputError::Read(path.to_path_buf(), e),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn default_path_is_per_year_and_day() {
+        assert_eq!(
+            PathBuf::from("/inputs/2023/day-06.txt"),
+            default_path(Path::new("/inputs"), 6)
+        );
+        assert_eq!(
+            PathBuf::from("/inputs/2023/day-16.txt"),
+            default_path(Path::new("/inputs"), 16)
+        );
+    }
+
+    #[test]
+    fn missing_file_is_reported() {
+        let path = Path::new("/definitely/not/here/day-01.txt");
+        let err = load(1, Some(path)).unwrap_err();
+        assert!(matches!(err, InputError::NotFound(ref p) if p == path));
+        assert_eq!(
+            "input file /definitely/not/here/day-01.txt does not exist",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn reads_given_path() {
+        let path = env::temp_dir().join("aoc-common-input-test.txt");
+        fs::write(&path, "1 2 3\n").unwrap();
+        assert_eq!("1 2 3\n", load(3, Some(&path)).unwrap());
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,14 @@
+pub mod answer;
+pub mod direction;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod polygon;
+pub mod profile;
+pub mod solution;
+pub mod timing;
+
+pub use answer::Answer;
+pub use direction::Direction;
+pub use parse::ParseError;
+pub use solution::Solution;
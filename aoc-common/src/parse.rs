@@ -0,0 +1,153 @@
+use std::{fmt, str::FromStr};
+
+/// Where and why an input failed to parse. `line` and `column` are 1-based;
+/// a `line` of 0 means the position is unknown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// `found` must be a slice of `source` so its position can be recovered.
+    /// A missing token is reported with an empty slice where it should start.
+    pub fn at(source: &str, found: &str, expected: impl Into<String>) -> Self {
+        let start = source.as_ptr() as usize;
+        let offset = (found.as_ptr() as usize).wrapping_sub(start);
+        let (line, column) = if offset <= source.len() {
+            let before = &source[..offset];
+            let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+            (
+                before.matches('\n').count() + 1,
+                before[line_start..].chars().count() + 1,
+            )
+        } else {
+            (0, 0)
+        };
+
+        Self {
+            line,
+            column,
+            snippet: found.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    pub fn at_end(source: &str, expected: impl Into<String>) -> Self {
+        Self::at(source, &source[source.len()..], expected)
+    }
+
+    /// Moves an error found while parsing a single line down to `line`.
+    pub fn on_line(mut self, line: usize) -> Self {
+        if self.line > 0 {
+            self.line += line - 1;
+        }
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line > 0 {
+            write!(f, "line {}, column {}: ", self.line, self.column)?;
+        }
+        write!(f, "expected {}, found ", self.expected)?;
+        if self.snippet.is_empty() {
+            f.write_str("end of line")
+        } else {
+            write!(f, "{:?}", self.snippet)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn parse_token<T: FromStr>(source: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(source, token, expected))
+}
+
+/// Parses every line of `input` with `T::from_str`, reporting errors at
+/// their line in `input`.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    parse_lines_with(input, str::parse)
+}
+
+pub fn parse_lines_with<T>(
+    input: &str,
+    parse: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| parse(line).map_err(|e| e.on_line(idx + 1)))
+        .collect()
+}
+
+pub fn get_list_of_nums<T: FromStr>(nums: &str) -> Result<Vec<T>, ParseError> {
+    nums.split_whitespace()
+        .map(|n| parse_token(nums, n, "a number"))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_whitespace_separated() {
+        assert_eq!(
+            Ok(vec![79u64, 14, 55, 13]),
+            get_list_of_nums::<u64>(" 79 14  55 13")
+        );
+        assert_eq!(Ok(vec![-3i32, 0, 5]), get_list_of_nums::<i32>("-3 0 5"));
+    }
+
+    #[test]
+    fn reports_offending_token() {
+        let err = get_list_of_nums::<u64>("79 1x 55").unwrap_err();
+        assert_eq!((1, 4), (err.line, err.column));
+        assert_eq!("1x", err.snippet);
+        assert_eq!(
+            "line 1, column 4: expected a number, found \"1x\"",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn locates_across_lines() {
+        let input = "1 2\n3 4\n5 x";
+        let err = ParseError::at(input, &input[10..], "a number");
+        assert_eq!((3, 3), (err.line, err.column));
+
+        let err = parse_lines::<Nums>(input).unwrap_err();
+        assert_eq!((3, 3, "x"), (err.line, err.column, err.snippet.as_str()));
+    }
+
+    #[test]
+    fn reports_missing_token() {
+        let err = ParseError::at_end("Game 1", "':'");
+        assert_eq!((1, 7), (err.line, err.column));
+        assert_eq!(
+            "line 1, column 7: expected ':', found end of line",
+            err.to_string()
+        );
+    }
+
+    #[derive(Debug)]
+    struct Nums;
+
+    impl FromStr for Nums {
+        type Err = ParseError;
+
+        fn from_str(line: &str) -> Result<Self, Self::Err> {
+            get_list_of_nums::<u8>(line).map(|_| Nums)
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use itertools::Itertools;
+
+/// Corners of a polygon on the integer lattice, `(x, y)`. The last corner
+/// joins back to the first, so the list may or may not repeat it.
+pub type Point = (i64, i64);
+
+/// Twice the signed area enclosed by `corners`, by the shoelace formula.
+pub fn double_area(corners: &[Point]) -> i64 {
+    corners
+        .iter()
+        .circular_tuple_windows()
+        .map(|(a, b)| a.0 * b.1 - b.0 * a.1)
+        .sum()
+}
+
+/// Lattice points on the edges of the polygon.
+pub fn boundary_points(corners: &[Point]) -> i64 {
+    corners
+        .iter()
+        .circular_tuple_windows()
+        .map(|(a, b)| gcd((b.0 - a.0).abs(), (b.1 - a.1).abs()))
+        .sum()
+}
+
+/// Lattice points strictly inside the polygon, by Pick's theorem:
+/// `A = i + b/2 - 1`.
+pub fn interior_points(corners: &[Point]) -> i64 {
+    (double_area(corners).abs() - boundary_points(corners)) / 2 + 1
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn counts_a_square() {
+        let square = [(0, 0), (4, 0), (4, 4), (0, 4)];
+        assert_eq!(32, double_area(&square));
+        assert_eq!(16, boundary_points(&square));
+        assert_eq!(9, interior_points(&square));
+
+        let closed = [(0, 0), (0, 4), (4, 4), (4, 0), (0, 0)];
+        assert_eq!(-32, double_area(&closed));
+        assert_eq!(9, interior_points(&closed));
+    }
+
+    #[test]
+    fn counts_slanted_edges() {
+        let triangle = [(0, 0), (4, 0), (0, 4)];
+        assert_eq!(12, boundary_points(&triangle));
+        assert_eq!(3, interior_points(&triangle));
+    }
+}
@@ -0,0 +1,46 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+pub const PROFILES_VAR: &str = "AOC_PROFILES";
+
+/// `$AOC_PROFILES`, or `profiles` under the current directory.
+pub fn default_root() -> PathBuf {
+    env::var_os(PROFILES_VAR).map_or_else(|| PathBuf::from("profiles"), PathBuf::from)
+}
+
+/// The directory holding `day`'s profiling reports, created if needed.
+pub fn day_dir(root: &Path, day: u8) -> io::Result<PathBuf> {
+    let dir = root.join(format!("day-{day:02}"));
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/// Where the per-day binaries write the report `name`, e.g.
+/// `profiles/day-14/part2-heap.json`.
+pub fn report_path(day: u8, name: &str) -> PathBuf {
+    let root = default_root();
+    match day_dir(&root, day) {
+        Ok(dir) => dir.join(name),
+        Err(e) => {
+            eprintln!("unable to create the profile directory for day {day}: {e}");
+            root.join(name)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn creates_day_dir() {
+        let root = env::temp_dir().join(format!("aoc-profiles-{}", std::process::id()));
+        let dir = day_dir(&root, 7).unwrap();
+
+        assert_eq!(root.join("day-07"), dir);
+        assert!(dir.is_dir());
+        fs::remove_dir_all(root).unwrap();
+    }
+}
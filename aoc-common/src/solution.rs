@@ -0,0 +1,53 @@
+use crate::{parse::ParseError, Answer};
+
+/// A day's puzzle, split into parsing the input once and solving each part
+/// against the parsed model.
+pub trait Solution {
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+    fn part1(parsed: &Self::Parsed) -> Answer;
+    fn part2(parsed: &Self::Parsed) -> Answer;
+
+    fn solve_part1(input: &str) -> Result<Answer, ParseError> {
+        Self::parse(input).map(|parsed| Self::part1(&parsed))
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer, ParseError> {
+        Self::parse(input).map(|parsed| Self::part2(&parsed))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            crate::parse::get_list_of_nums(input)
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Answer {
+            parsed.iter().sum::<u32>().into()
+        }
+
+        fn part2(parsed: &Self::Parsed) -> Answer {
+            parsed.iter().product::<u32>().into()
+        }
+    }
+
+    #[test]
+    fn solves_through_parse() {
+        assert_eq!(Ok(Answer::Unsigned(9)), Sum::solve_part1("2 3 4"));
+        assert_eq!(Ok(Answer::Unsigned(24)), Sum::solve_part2("2 3 4"));
+        let input = "2 x";
+        assert_eq!(
+            Err(ParseError::at(input, &input[2..], "a number")),
+            Sum::solve_part1(input)
+        );
+    }
+}
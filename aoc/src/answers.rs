@@ -0,0 +1,139 @@
+use std::{
+    collections::BTreeMap,
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use aoc_common::{
+    input::{INPUTS_VAR, YEAR},
+    Answer,
+};
+use serde::Deserialize;
+
+/// Accepted answers, keyed by day and then part:
+///
+/// ```toml
+/// [day-04]
+/// part1 = 21821
+/// part2 = 5539496
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers(BTreeMap<String, DayAnswers>);
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+impl Answers {
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        let answers = self.0.get(&format!("day-{day:02}"))?;
+        match part {
+            1 => answers.part1.as_ref(),
+            2 => answers.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = toml::de::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        toml::from_str(s)
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    NotFound(PathBuf),
+    Read(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+    NoAnswersFile,
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::NotFound(path) => {
+                write!(f, "answers file {} does not exist", path.display())
+            }
+            AnswersError::Read(path, e) => write!(f, "unable to read {}: {e}", path.display()),
+            AnswersError::Parse(path, e) => write!(f, "unable to parse {}: {e}", path.display()),
+            AnswersError::NoAnswersFile => write!(
+                f,
+                "no answers file: pass --answers <path>, or set {INPUTS_VAR} to a directory \
+                 containing {}",
+                default_name().display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+/// Loads `path`, or `$AOC_INPUTS/2023/answers.toml` so the answers live
+/// next to the inputs they were accepted for.
+pub fn load(path: Option<&Path>) -> Result<Answers, AnswersError> {
+    let path = match path {
+        Some(path) => path.to_path_buf(),
+        None => env::var_os(INPUTS_VAR)
+            .map(|dir| Path::new(&dir).join(default_name()))
+            .ok_or(AnswersError::NoAnswersFile)?,
+    };
+
+    let contents = fs::read_to_string(&path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => AnswersError::NotFound(path.clone()),
+        _ => AnswersError::Read(path.clone(), e),
+    })?;
+    contents.parse().map_err(|e| AnswersError::Parse(path, e))
+}
+
+fn default_name() -> PathBuf {
+    PathBuf::from(YEAR.to_string()).join("answers.toml")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn looks_up_by_day_and_part() {
+        let answers: Answers = r#"
+            [day-04]
+            part1 = 21821
+            part2 = 5539496
+
+            [day-18]
+            part2 = "952408144115"
+        "#
+        .parse()
+        .unwrap();
+
+        assert_eq!(Some(&Answer::Unsigned(21821)), answers.get(4, 1));
+        assert_eq!(Some(&Answer::Unsigned(5539496)), answers.get(4, 2));
+        assert_eq!(None, answers.get(18, 1));
+        assert!(matches!(
+            answers.get(18, 2),
+            Some(Answer::BigInt(n)) if n.to_string() == "952408144115"
+        ));
+        assert_eq!(None, answers.get(5, 1));
+    }
+
+    #[test]
+    fn quoted_answers_equal_the_solver_numbers() {
+        let answers: Answers = "[day-18]\npart2 = \"952408144115\"".parse().unwrap();
+
+        // A solver returns a plain number, which must still verify.
+        assert_eq!(Some(&Answer::Signed(952408144115)), answers.get(18, 2));
+        assert_eq!(Some(&Answer::Unsigned(952408144115)), answers.get(18, 2));
+    }
+
+    #[test]
+    fn rejects_unknown_parts() {
+        assert!("[day-04]\npart3 = 1".parse::<Answers>().is_err());
+    }
+}
@@ -0,0 +1,260 @@
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    fmt, fs, io,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc_common::{input, timing::timed};
+use serde::{Deserialize, Serialize};
+
+use crate::{panic_reason, registry::Day};
+
+/// Timings of one step over repeated runs, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: u64,
+    pub median: u64,
+    pub p95: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Self> {
+        samples.sort_unstable();
+        // Nearest-rank percentile, so every value is an observed run.
+        let rank = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+        let nanos = |d: Duration| d.as_nanos() as u64;
+        (!samples.is_empty()).then(|| Stats {
+            min: nanos(samples[0]),
+            median: nanos(rank(50)),
+            p95: nanos(rank(95)),
+        })
+    }
+
+    /// How much slower this median is than `baseline`'s, in percent.
+    pub fn slowdown(&self, baseline: &Stats) -> f64 {
+        (self.median as f64 / baseline.median.max(1) as f64 - 1.0) * 100.0
+    }
+}
+
+/// Stats keyed by day and then step (`parse`, `part1` or `part2`), in the
+/// same shape as the answers file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Results(BTreeMap<String, BTreeMap<String, Stats>>);
+
+impl Results {
+    pub fn get(&self, day: u8, step: &str) -> Option<&Stats> {
+        self.0.get(&day_key(day))?.get(step)
+    }
+
+    fn insert(&mut self, day: u8, step: &str, stats: Stats) {
+        self.0
+            .entry(day_key(day))
+            .or_default()
+            .insert(step.to_string(), stats);
+    }
+
+    pub fn load(path: &Path) -> Result<Self, BenchError> {
+        let contents =
+            fs::read_to_string(path).map_err(|e| BenchError::Io(path.to_path_buf(), e))?;
+        serde_json::from_str(&contents).map_err(|e| BenchError::Parse(path.to_path_buf(), e))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BenchError> {
+        let json = serde_json::to_string_pretty(self).expect("stats always serialize");
+        fs::write(path, json + "\n").map_err(|e| BenchError::Io(path.to_path_buf(), e))
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day-{day:02}")
+}
+
+#[derive(Debug)]
+pub enum BenchError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, serde_json::Error),
+}
+
+impl fmt::Display for BenchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BenchError::Io(path, e) => write!(f, "{}: {e}", path.display()),
+            BenchError::Parse(path, e) => {
+                write!(f, "{} is not a saved benchmark: {e}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for BenchError {}
+
+pub struct Report {
+    pub results: Results,
+    pub failed: usize,
+    pub slower: usize,
+}
+
+/// Times `runs` parses of each day's input, then `runs` solves of each part
+/// against the last parse, printing a row per step as it finishes.
+pub fn run(days: &[&Day], runs: u32, baseline: Option<&Results>, threshold: f64) -> Report {
+    let mut report = Report {
+        results: Results::default(),
+        failed: 0,
+        slower: 0,
+    };
+    print!(
+        "{:<4}{:<7}{:>12}{:>12}{:>12}",
+        "day", "step", "min", "median", "p95"
+    );
+    println!("{:>12}", if baseline.is_some() { "baseline" } else { "" });
+
+    for day in days {
+        let input = match input::load(day.day, None) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("day {:02}: {e}", day.day);
+                report.failed += 3;
+                continue;
+            }
+        };
+
+        let parsed = repeat(runs, || {
+            day.solution
+                .parse(&input)
+                .map_err(|e| format!("unable to parse input: {e}"))
+        });
+        let parsed = match parsed {
+            Ok((parsed, stats)) => {
+                report.record(day.day, "parse", stats, baseline, threshold);
+                parsed
+            }
+            Err(reason) => {
+                eprintln!("day {:02} parse: failed: {reason}", day.day);
+                report.failed += 3;
+                continue;
+            }
+        };
+
+        for (part, step) in [(1, "part1"), (2, "part2")] {
+            match repeat(runs, || {
+                day.solution.solve(parsed.as_ref(), part).into_result()
+            }) {
+                Ok((_, stats)) => report.record(day.day, step, stats, baseline, threshold),
+                Err(reason) => {
+                    eprintln!("day {:02} {step}: failed: {reason}", day.day);
+                    report.failed += 1;
+                }
+            }
+        }
+    }
+    report
+}
+
+impl Report {
+    fn record(
+        &mut self,
+        day: u8,
+        step: &str,
+        stats: Stats,
+        baseline: Option<&Results>,
+        threshold: f64,
+    ) {
+        let fmt = |nanos: u64| format!("{:.2?}", Duration::from_nanos(nanos));
+        print!(
+            "{:<4}{step:<7}{:>12}{:>12}{:>12}",
+            format!("{day:02}"),
+            fmt(stats.min),
+            fmt(stats.median),
+            fmt(stats.p95)
+        );
+        match baseline.and_then(|b| b.get(day, step)) {
+            Some(old) => {
+                let slowdown = stats.slowdown(old);
+                print!("{:>12} ({slowdown:+.1}%)", fmt(old.median));
+                if slowdown > threshold {
+                    print!("  SLOWER");
+                    self.slower += 1;
+                }
+                println!();
+            }
+            None if baseline.is_some() => println!("{:>12}", "-"),
+            None => println!(),
+        }
+        self.results.insert(day, step, stats);
+    }
+}
+
+/// Runs `f` `runs` times, stopping at the first error or panic.
+fn repeat<T>(runs: u32, f: impl Fn() -> Result<T, String>) -> Result<(T, Stats), String> {
+    let mut samples = Vec::with_capacity(runs as usize);
+    let mut last = None;
+    for _ in 0..runs {
+        let (result, elapsed) = timed(|| panic::catch_unwind(AssertUnwindSafe(&f)));
+        let value = result
+            .map_err(|payload: Box<dyn Any + Send>| panic_reason(&*payload).to_string())??;
+        samples.push(elapsed);
+        last = Some(value);
+    }
+    let stats = Stats::from_samples(&mut samples).expect("at least one run");
+    Ok((last.expect("at least one run"), stats))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn nearest_rank_stats() {
+        let mut samples: Vec<Duration> = (1..=20).rev().map(Duration::from_nanos).collect();
+        assert_eq!(
+            Some(Stats {
+                min: 1,
+                median: 10,
+                p95: 19
+            }),
+            Stats::from_samples(&mut samples)
+        );
+
+        let mut single = [Duration::from_nanos(7)];
+        assert_eq!(
+            Some(Stats {
+                min: 7,
+                median: 7,
+                p95: 7
+            }),
+            Stats::from_samples(&mut single)
+        );
+        assert_eq!(None, Stats::from_samples(&mut []));
+    }
+
+    #[test]
+    fn compares_medians() {
+        let stats = |median| Stats {
+            min: 0,
+            median,
+            p95: 0,
+        };
+        assert_eq!(50.0, stats(150).slowdown(&stats(100)));
+        assert_eq!(-50.0, stats(50).slowdown(&stats(100)));
+    }
+
+    #[test]
+    fn round_trips_results() {
+        let mut results = Results::default();
+        let stats = Stats {
+            min: 1,
+            median: 2,
+            p95: 3,
+        };
+        results.insert(4, "part2", stats);
+
+        let json = serde_json::to_string(&results).unwrap();
+        assert_eq!(r#"{"day-04":{"part2":{"min":1,"median":2,"p95":3}}}"#, json);
+        let results: Results = serde_json::from_str(&json).unwrap();
+        assert_eq!(Some(&stats), results.get(4, "part2"));
+        assert_eq!(None, results.get(4, "parse"));
+    }
+}
@@ -0,0 +1,361 @@
+use std::{
+    any::Any,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use aoc_common::{input, profile::day_dir, timing::timed, Answer};
+use clap::{Args, Parser, Subcommand};
+
+use registry::Day;
+
+mod answers;
+mod bench;
+mod profile;
+mod registry;
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions.")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day (optionally one part), or every registered day with --all.
+    Run(RunArgs),
+    /// Run every registered day and compare against the recorded answers.
+    Verify(VerifyArgs),
+    /// Time parsing and each part over repeated runs.
+    Bench(BenchArgs),
+    /// Profile heap usage or ad hoc events with dhat, one report per part.
+    Profile(ProfileArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Only run this part, both parts are run when omitted.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Puzzle input file, `-` reads stdin. Defaults to $AOC_INPUTS/2023/day-XX.txt.
+    #[arg(long, short, requires = "day")]
+    input: Option<PathBuf>,
+
+    #[arg(long)]
+    all: bool,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this day.
+    #[arg(long)]
+    day: Option<u8>,
+
+    /// Recorded answers. Defaults to $AOC_INPUTS/2023/answers.toml.
+    #[arg(long)]
+    answers: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only benchmark this day.
+    #[arg(long)]
+    day: Option<u8>,
+
+    /// Timed runs of each parse and part.
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Save the results here, to be passed as a later --baseline.
+    #[arg(long)]
+    save: Option<PathBuf>,
+
+    /// Compare medians against results saved earlier with --save.
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// Percentage a median may grow over the baseline before it is flagged.
+    #[arg(long, default_value_t = 10.0, requires = "baseline")]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct ProfileArgs {
+    /// Only profile this day.
+    #[arg(long)]
+    day: Option<u8>,
+
+    /// Only profile this part, both parts are profiled when omitted.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    #[arg(long, value_enum, default_value_t = profile::Mode::Heap)]
+    mode: profile::Mode,
+
+    /// Reports go to <OUT>/day-XX. Defaults to $AOC_PROFILES, or ./profiles.
+    #[arg(long)]
+    out: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    // Failing parts are reported by `solve_part`, keep the default hook quiet.
+    panic::set_hook(Box::new(|_| {}));
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+        Command::Profile(args) => profile(args),
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let Some(days) = select_days(args.day) else {
+        return ExitCode::FAILURE;
+    };
+
+    let parts: Vec<u8> = args.part.map_or(vec![1, 2], |p| vec![p]);
+    let mut failed = 0;
+    for day in &days {
+        let parsed = match parse_day(day, args.input.as_deref()) {
+            Ok((parsed, elapsed)) => {
+                println!("day {:02} parse: {elapsed:.2?}", day.day);
+                parsed
+            }
+            Err(reason) => {
+                eprintln!("day {:02}: {reason}", day.day);
+                failed += parts.len();
+                continue;
+            }
+        };
+
+        for &part in &parts {
+            match solve_part(day, part, parsed.as_ref()) {
+                (Ok(answer), elapsed) => {
+                    println!("day {:02} part {part}: {answer} ({elapsed:.2?})", day.day)
+                }
+                (Err(reason), _) => {
+                    eprintln!("day {:02} part {part}: failed: {reason}", day.day);
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    if failed > 0 {
+        eprintln!("{failed} of {} parts failed", days.len() * parts.len());
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify(args: VerifyArgs) -> ExitCode {
+    let Some(days) = select_days(args.day) else {
+        return ExitCode::FAILURE;
+    };
+    let answers = match answers::load(args.answers.as_deref()) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut wrong, mut unrecorded) = (0, 0);
+    for day in &days {
+        let parsed = match parse_day(day, None) {
+            Ok((parsed, _)) => parsed,
+            Err(reason) => {
+                eprintln!("day {:02}: {reason}", day.day);
+                wrong += 2;
+                continue;
+            }
+        };
+
+        for part in [1, 2] {
+            let answer = solve_part(day, part, parsed.as_ref()).0;
+            match (answer, answers.get(day.day, part)) {
+                (Ok(answer), Some(expected)) if answer == *expected => {
+                    println!("day {:02} part {part}: ok", day.day)
+                }
+                (Ok(answer), Some(expected)) => {
+                    eprintln!(
+                        "day {:02} part {part}: expected {expected}, got {answer}",
+                        day.day
+                    );
+                    wrong += 1;
+                }
+                (Ok(answer), None) => {
+                    println!(
+                        "day {:02} part {part}: {answer} (no recorded answer)",
+                        day.day
+                    );
+                    unrecorded += 1;
+                }
+                (Err(reason), _) => {
+                    eprintln!("day {:02} part {part}: failed: {reason}", day.day);
+                    wrong += 1;
+                }
+            }
+        }
+    }
+
+    let total = days.len() * 2;
+    println!(
+        "{} of {total} parts verified, {unrecorded} without a recorded answer",
+        total - wrong - unrecorded
+    );
+    if wrong > 0 {
+        eprintln!("{wrong} of {total} parts failed or did not match");
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn bench(args: BenchArgs) -> ExitCode {
+    let Some(days) = select_days(args.day) else {
+        return ExitCode::FAILURE;
+    };
+    let baseline = match args.baseline.as_deref().map(bench::Results::load) {
+        Some(Ok(baseline)) => Some(baseline),
+        Some(Err(e)) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+        None => None,
+    };
+
+    let report = bench::run(&days, args.runs, baseline.as_ref(), args.threshold);
+    if let Some(path) = &args.save {
+        if let Err(e) = report.results.save(path) {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if report.failed > 0 {
+        eprintln!("{} steps failed", report.failed);
+    }
+    if report.slower > 0 {
+        eprintln!(
+            "{} steps are more than {}% slower than the baseline",
+            report.slower, args.threshold
+        );
+    }
+    if report.failed > 0 || report.slower > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn profile(args: ProfileArgs) -> ExitCode {
+    if let Err(e) = args.mode.check_enabled() {
+        eprintln!("{e}");
+        return ExitCode::FAILURE;
+    }
+    let Some(days) = select_days(args.day) else {
+        return ExitCode::FAILURE;
+    };
+    let root = args.out.unwrap_or_else(aoc_common::profile::default_root);
+
+    let parts: Vec<u8> = args.part.map_or(vec![1, 2], |p| vec![p]);
+    let mut failed = 0;
+    for day in &days {
+        let loaded = input::load(day.day, None)
+            .map_err(|e| e.to_string())
+            .and_then(|input| Ok((input, day_dir(&root, day.day).map_err(|e| e.to_string())?)));
+        let (input, dir) = match loaded {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                eprintln!("day {:02}: {e}", day.day);
+                failed += parts.len();
+                continue;
+            }
+        };
+
+        let mut summary = String::new();
+        for &part in &parts {
+            match profile::profile(day, part, &input, args.mode, &dir) {
+                Ok(usage) => {
+                    println!("day {:02} part {part}: {usage}", day.day);
+                    summary += &format!("part{part}: {usage}\n");
+                }
+                Err(reason) => {
+                    eprintln!("day {:02} part {part}: failed: {reason}", day.day);
+                    failed += 1;
+                }
+            }
+        }
+        let path = dir.join(format!("summary-{}.txt", args.mode.name()));
+        if let Err(e) = fs::write(&path, summary) {
+            eprintln!("unable to write {}: {e}", path.display());
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        eprintln!("{failed} parts failed");
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn select_days(day: Option<u8>) -> Option<Vec<&'static Day>> {
+    match day {
+        Some(day) => match registry::find(day) {
+            Some(d) => Some(vec![d]),
+            None => {
+                eprintln!("day {day} is not registered");
+                None
+            }
+        },
+        None => Some(registry::DAYS.iter().collect()),
+    }
+}
+
+/// Loads and parses the day's input, describing any failure.
+fn parse_day(day: &Day, input: Option<&Path>) -> Result<(Box<dyn Any>, Duration), String> {
+    let input = input::load(day.day, input).map_err(|e| e.to_string())?;
+    let (parsed, elapsed) =
+        timed(|| panic::catch_unwind(AssertUnwindSafe(|| day.solution.parse(&input))));
+    match parsed {
+        Ok(Ok(parsed)) => Ok((parsed, elapsed)),
+        Ok(Err(e)) => Err(format!("unable to parse input: {e}")),
+        Err(payload) => Err(format!("parsing failed: {}", panic_reason(&*payload))),
+    }
+}
+
+fn solve_part(day: &Day, part: u8, parsed: &dyn Any) -> (Result<Answer, String>, Duration) {
+    let (result, elapsed) =
+        timed(|| panic::catch_unwind(AssertUnwindSafe(|| day.solution.solve(parsed, part))));
+    (
+        result
+            .map_err(|payload| panic_reason(&*payload).to_string())
+            .and_then(Answer::into_result),
+        elapsed,
+    )
+}
+
+fn panic_reason(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("panicked")
+}
@@ -0,0 +1,121 @@
+use std::{
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+};
+
+use aoc_common::ParseError;
+use clap::ValueEnum;
+
+use crate::{panic_reason, registry::Day};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Mode {
+    /// Every allocation, needs the `dhat-heap` feature.
+    Heap,
+    /// Events counted in the hot loops, needs the `dhat-ad-hoc` feature.
+    AdHoc,
+}
+
+impl Mode {
+    pub fn name(self) -> &'static str {
+        match self {
+            Mode::Heap => "heap",
+            Mode::AdHoc => "ad-hoc",
+        }
+    }
+
+    /// Profiling without the matching feature would silently record nothing.
+    pub fn check_enabled(self) -> Result<(), String> {
+        let enabled = match self {
+            Mode::Heap => cfg!(feature = "dhat-heap"),
+            Mode::AdHoc => cfg!(feature = "dhat-ad-hoc"),
+        };
+        if enabled {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} profiling needs a build with `--features dhat-{}`",
+                self.name(),
+                self.name()
+            ))
+        }
+    }
+}
+
+/// What one part used while it parsed and solved.
+pub enum Usage {
+    Heap {
+        total_bytes: u64,
+        total_blocks: u64,
+        max_bytes: usize,
+        max_blocks: usize,
+    },
+    AdHoc {
+        total_events: u64,
+        total_units: u64,
+    },
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Usage::Heap {
+                total_bytes,
+                total_blocks,
+                max_bytes,
+                max_blocks,
+            } => write!(
+                f,
+                "{total_bytes} bytes in {total_blocks} blocks total, \
+                 {max_bytes} bytes in {max_blocks} blocks at peak"
+            ),
+            Usage::AdHoc {
+                total_events,
+                total_units,
+            } => write!(f, "{total_events} events, {total_units} units"),
+        }
+    }
+}
+
+/// Parses `input` and solves `part` under a profiler, writing its report to
+/// `dir/partN-<mode>.json`.
+pub fn profile(day: &Day, part: u8, input: &str, mode: Mode, dir: &Path) -> Result<Usage, String> {
+    let builder =
+        dhat::Profiler::builder().file_name(dir.join(format!("part{part}-{}.json", mode.name())));
+    let profiler = match mode {
+        Mode::Heap => builder.build(),
+        Mode::AdHoc => builder.ad_hoc().build(),
+    };
+
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+        let parsed = day.solution.parse(input)?;
+        Ok::<_, ParseError>(day.solution.solve(parsed.as_ref(), part).into_result())
+    }));
+    let usage = match mode {
+        Mode::Heap => {
+            let stats = dhat::HeapStats::get();
+            Usage::Heap {
+                total_bytes: stats.total_bytes,
+                total_blocks: stats.total_blocks,
+                max_bytes: stats.max_bytes,
+                max_blocks: stats.max_blocks,
+            }
+        }
+        Mode::AdHoc => {
+            let stats = dhat::AdHocStats::get();
+            Usage::AdHoc {
+                total_events: stats.total_events,
+                total_units: stats.total_units,
+            }
+        }
+    };
+    drop(profiler);
+
+    match solved {
+        Ok(Ok(Ok(_))) => Ok(usage),
+        Ok(Ok(Err(reason))) => Err(reason),
+        Ok(Err(e)) => Err(format!("unable to parse input: {e}")),
+        Err(payload) => Err(panic_reason(&*payload).to_string()),
+    }
+}
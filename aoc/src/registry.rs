@@ -0,0 +1,88 @@
+use std::any::Any;
+
+use aoc_common::{Answer, ParseError, Solution};
+
+/// Object safe view of a [`Solution`] so days with different parsed types
+/// can share one registry.
+pub trait Runnable: Sync {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    fn solve(&self, parsed: &dyn Any, part: u8) -> Answer;
+}
+
+impl<S> Runnable for S
+where
+    S: Solution + Sync,
+    S::Parsed: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        S::parse(input).map(|parsed| Box::new(parsed) as Box<dyn Any>)
+    }
+
+    fn solve(&self, parsed: &dyn Any, part: u8) -> Answer {
+        let parsed = parsed
+            .downcast_ref::<S::Parsed>()
+            .expect("parsed by the same solution");
+        match part {
+            1 => S::part1(parsed),
+            2 => S::part2(parsed),
+            _ => panic!("there is no part {part}"),
+        }
+    }
+}
+
+pub struct Day {
+    pub day: u8,
+    pub solution: &'static dyn Runnable,
+}
+
+pub const DAYS: &[Day] = &[
+    Day { day: 0, solution: &day_00::Day00 },
+    Day { day: 1, solution: &day_01::Day01 },
+    Day { day: 2, solution: &day_02::Day02 },
+    Day { day: 3, solution: &day_03::Day03 },
+    Day { day: 4, solution: &day_04::Day04 },
+    Day { day: 5, solution: &day_05::Day05 },
+    Day { day: 6, solution: &day_06::Day06 },
+    Day { day: 7, solution: &day_07::Day07 },
+    Day { day: 8, solution: &day_08::Day08 },
+    Day { day: 9, solution: &day_09::Day09 },
+    Day { day: 10, solution: &day_10::Day10 },
+    Day { day: 11, solution: &day_11::Day11 },
+    Day { day: 12, solution: &day_12::Day12 },
+    Day { day: 13, solution: &day_13::Day13 },
+    Day { day: 14, solution: &day_14::Day14 },
+    Day { day: 15, solution: &day_15::Day15 },
+    Day { day: 16, solution: &day_16::Day16 },
+    Day { day: 18, solution: &day_18::Day18 },
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn days_are_registered_in_order() {
+        assert!(DAYS.windows(2).all(|w| w[0].day < w[1].day));
+    }
+
+    #[test]
+    fn finds_registered_day() {
+        assert_eq!(Some(16), find(16).map(|d| d.day));
+        assert!(find(17).is_none());
+    }
+
+    #[test]
+    fn solves_parsed_input() {
+        let day = find(6).unwrap();
+        let parsed = day
+            .solution
+            .parse("Time:      7  15   30\nDistance:  9  40  200")
+            .unwrap();
+        assert_eq!(Answer::Unsigned(288), day.solution.solve(parsed.as_ref(), 1));
+        assert_eq!(Answer::Unsigned(71503), day.solution.solve(parsed.as_ref(), 2));
+    }
+}
@@ -1,43 +1,8 @@
+use aoc_common::{input::exit_with_error, Solution};
+use day_00::Day00;
+
 fn main() {
-    let input = include_str!("./input1.txt");
-    let output = part1(input);
+    let input = aoc_common::input::from_args(0);
+    let output = Day00::solve_part1(&input).unwrap_or_else(|e| exit_with_error(e));
     dbg!(output);
 }
-
-fn part1(input: &str) -> String {
-    input
-        .split("\n\n")
-        .map(|elf_inventory| {
-            elf_inventory.lines().map(|line| {
-                line.parse::<usize>()
-                    .expect("Unable to parse number from line.")
-            })
-        })
-        .map(|per_elf| per_elf.sum::<usize>())
-        .max()
-        .expect("Unable to find max.")
-        .to_string()
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    #[test]
-    fn example_input() {
-        let input = "1000
-2000
-3000
-
-4000
-
-5000
-6000
-
-7000
-8000
-9000
-
-10000";
-        assert_eq!("24000", part1(input));
-    }
-}
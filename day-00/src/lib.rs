@@ -0,0 +1,30 @@
+use aoc_common::{parse::parse_token, Answer, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    type Parsed = Vec<Vec<usize>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input
+            .split("\n\n")
+            .map(|elf_inventory| {
+                elf_inventory
+                    .lines()
+                    .map(|line| parse_token(input, line, "a number"))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::part2(parsed)
+    }
+}
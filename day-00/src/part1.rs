@@ -0,0 +1,36 @@
+use aoc_common::Answer;
+
+pub fn part1(inventories: &[Vec<usize>]) -> Answer {
+    inventories
+        .iter()
+        .map(|per_elf| per_elf.iter().sum::<usize>())
+        .max()
+        .expect("Unable to find max.")
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day00;
+
+    #[test]
+    fn example_input() {
+        let input = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+        assert_eq!(Answer::Unsigned(24000), Day00::solve_part1(input).unwrap());
+    }
+}
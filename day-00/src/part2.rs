@@ -0,0 +1,36 @@
+use aoc_common::Answer;
+
+pub fn part2(inventories: &[Vec<usize>]) -> Answer {
+    let mut elf_carrying = inventories
+        .iter()
+        .map(|per_elf| per_elf.iter().sum::<usize>())
+        .collect::<Vec<usize>>();
+    elf_carrying.sort_unstable();
+    elf_carrying.iter().rev().take(3).sum::<usize>().into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day00;
+
+    #[test]
+    fn example_input() {
+        let input = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+        assert_eq!(Answer::Unsigned(45000), Day00::solve_part2(input).unwrap());
+    }
+}
@@ -1,35 +1,8 @@
+use aoc_common::{input::exit_with_error, Solution};
+use day_01::Day01;
+
 fn main() {
-    let input = include_str!("./input1.txt");
-    let output = part1(input);
+    let input = aoc_common::input::from_args(1);
+    let output = Day01::solve_part1(&input).unwrap_or_else(|e| exit_with_error(e));
     dbg!(output);
 }
-
-fn part1(input: &str) -> String {
-    input
-        .lines()
-        .map(|line| {
-            let first_digit = line.chars()
-                .find(|ch: &char| char::is_ascii_digit(ch))
-                .expect("Unable to locate first digit");
-            let last_digit = line.chars()
-                .rfind(|ch: &char| char::is_ascii_digit(ch))
-                .expect("Unable to locate last digit");
-            format!("{}{}", first_digit, last_digit)
-        })
-        .map(|digits| digits.parse::<u32>().expect("Unable to parse string into digits."))
-        .sum::<u32>()
-        .to_string()
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    #[test]
-    fn example_input() {
-        let sample = "1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet";
-        assert_eq!("142", part1(sample));
-    }
-}
@@ -0,0 +1,90 @@
+use aoc_common::{parse::parse_lines_with, Answer, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = Vec<Calibration>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines_with(input, Calibration::parse)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::part2(parsed)
+    }
+}
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The first and last digit on a line of the calibration document.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Calibration {
+    /// Counting only `0`-`9`, which a line may not have.
+    pub digits: Option<(u32, u32)>,
+    /// Also counting spelled out digits, which can overlap as in `twone`.
+    pub words: (u32, u32),
+}
+
+impl Calibration {
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let digits: Vec<u32> = line.chars().filter_map(|ch| ch.to_digit(10)).collect();
+        let words: Vec<u32> = line
+            .char_indices()
+            .filter_map(|(idx, ch)| {
+                let rest = &line[idx..];
+                ch.to_digit(10).or_else(|| {
+                    (1..)
+                        .zip(WORDS)
+                        .find_map(|(n, word)| rest.starts_with(word).then_some(n))
+                })
+            })
+            .collect();
+
+        let first_and_last = |digits: &[u32]| Some((*digits.first()?, *digits.last()?));
+        Ok(Calibration {
+            digits: first_and_last(&digits),
+            words: first_and_last(&words)
+                .ok_or_else(|| ParseError::at(line, line, "a digit or a spelled out digit"))?,
+        })
+    }
+}
+
+/// The two digits read as one number.
+pub fn value((first, last): (u32, u32)) -> u32 {
+    first * 10 + last
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn finds_first_and_last_digits() {
+        assert_eq!(
+            Ok(Calibration {
+                digits: Some((3, 3)),
+                words: (3, 1)
+            }),
+            Calibration::parse("x3fourtwone")
+        );
+        assert_eq!(None, Calibration::parse("eightwothree").unwrap().digits);
+    }
+
+    #[test]
+    fn rejects_lines_without_digits() {
+        let err = Day01::parse("1abc2\npqrstu").unwrap_err();
+        assert_eq!(
+            "line 2, column 1: expected a digit or a spelled out digit, found \"pqrstu\"",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use aoc_common::Answer;
+
+use crate::{value, Calibration};
+
+pub fn part1(calibrations: &[Calibration]) -> Answer {
+    calibrations
+        .iter()
+        .enumerate()
+        .map(|(idx, c)| {
+            c.digits
+                .map(value)
+                .ok_or_else(|| format!("line {} has no digit", idx + 1))
+        })
+        .sum::<Result<u32, _>>()
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day01;
+
+    #[test]
+    fn example_input() {
+        let sample = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+        assert_eq!(Answer::Unsigned(142), Day01::solve_part1(sample).unwrap());
+    }
+
+    #[test]
+    fn spelled_digits_alone_are_unsolved() {
+        assert_eq!(
+            Answer::Unsolved("line 2 has no digit".to_string()),
+            Day01::solve_part1("1abc2\neightwothree").unwrap()
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use aoc_common::Answer;
+use regex::Regex;
+
+use crate::{value, Calibration};
+
+pub fn part2(calibrations: &[Calibration]) -> Answer {
+    calibrations
+        .iter()
+        .map(|c| value(c.words))
+        .sum::<u32>()
+        .into()
+}
+
+fn convert_to_num_rep(possible_num: &str) -> &str {
+    match possible_num {
+        "one" => "1",
+        "two" => "2",
+        "three" => "3",
+        "four" => "4",
+        "five" => "5",
+        "six" => "6",
+        "seven" => "7",
+        "eight" => "8",
+        "nine" => "9",
+        _ => possible_num
+    }
+}
+
+#[allow(dead_code)]
+fn locate_by_reg(line: &str) -> Option<String> {
+    // This doesn't work because of overlapping digits, see overlapping_ending.
+    let re = Regex::new(r"([0-9]|one|two|three|four|five|six|seven|eight|nine)").unwrap();
+    let matches: Vec<_> = re.find_iter(line)
+        .map(|m| m.as_str())
+        .map(convert_to_num_rep)
+        .collect();
+
+    matches
+        .first()
+        .zip(matches.last())
+        .map(|(first, last)| format!("{}{}", first, last))
+}
+
+#[allow(dead_code)]
+fn locate_by_ascii_digit(line: &str) -> Option<String> {
+    let first_digit = line.chars().find(char::is_ascii_digit)?;
+    let last_digit = line.chars().rfind(char::is_ascii_digit)?;
+
+    Some(format!("{}{}", first_digit, last_digit))
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use super::*;
+    use crate::Day01;
+
+    #[test]
+    fn example_input() {
+        let sample = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+        assert_eq!(Answer::Unsigned(281), Day01::solve_part2(sample).unwrap());
+    }
+
+    #[test]
+    fn overlapping_ending() {
+        let sample = "x3fourtwone";
+        // the regex would return 32.
+        assert_eq!(Answer::Unsigned(31), Day01::solve_part2(sample).unwrap());
+    }
+
+    #[test]
+    fn bigger_sample() {
+        let s1 = "9dlvndqbddghpxc
+rtkrbtthree8sixfoureight6
+fdxrqmfxdkstpmcj7lmphgsmqqnmjrtwo3tcbc
+onetjcsmgk57nvmkvcvkdtqtsksgpchsfsjzkkmb
+six8threepvlxttc85two
+";
+        // 99 <-- apparently if there is only 1 digit it you can reuse it. 
+        // 36
+        // 73
+        // 17
+        // 62
+        assert_eq!(Answer::Unsigned(287), Day01::solve_part2(s1).unwrap());
+        
+    }
+
+    #[test]
+    fn converts_digits() {
+        assert_eq!("0", convert_to_num_rep("0"));
+        assert_eq!("1", convert_to_num_rep("one"));
+    }
+}
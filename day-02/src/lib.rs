@@ -0,0 +1,126 @@
+use std::{cmp::max, str::FromStr};
+
+use aoc_common::{
+    parse::{parse_lines, parse_token},
+    Answer, ParseError, Solution,
+};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::part2(parsed)
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Game {
+    pub id: u8,
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl Game {
+    fn parse_game_id(line: &str, game_id_chunk: &str) -> Result<u8, ParseError> {
+        let id = game_id_chunk
+            .strip_prefix("Game ")
+            .ok_or_else(|| ParseError::at(line, game_id_chunk, "'Game '"))?;
+        parse_token(line, id, "a game id")
+    }
+
+    fn parse_blocks(line: &str, blocks: &str) -> Result<(u32, u32, u32), ParseError> {
+        blocks
+            .split_terminator(',')
+            .map(str::trim)
+            .try_fold((0, 0, 0), |mut acc, b| {
+                if let Some(n) = b.strip_suffix(" red") {
+                    acc.0 = parse_token(line, n, "a number of red cubes")?;
+                } else if let Some(n) = b.strip_suffix(" green") {
+                    acc.1 = parse_token(line, n, "a number of green cubes")?;
+                } else if let Some(n) = b.strip_suffix(" blue") {
+                    acc.2 = parse_token(line, n, "a number of blue cubes")?;
+                } else {
+                    return Err(ParseError::at(line, b, "a red, green or blue count"));
+                }
+                Ok(acc)
+            })
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut it = line.split_terminator(&[':', ';']);
+        let game_id = Game::parse_game_id(line, it.next().unwrap_or(line))?;
+        if !line.contains(':') {
+            return Err(ParseError::at_end(line, "':'"));
+        }
+
+        it.map(|s| Game::parse_blocks(line, s.trim())).try_fold(
+            Game {
+                id: game_id,
+                red: 0,
+                green: 0,
+                blue: 0,
+            },
+            |mut acc, b| {
+                let b = b?;
+                acc.red = max(acc.red, b.0);
+                acc.green = max(acc.green, b.1);
+                acc.blue = max(acc.blue, b.2);
+                Ok(acc)
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn parse_block_colors() {
+        let sample = "3 blue, 4 red, 2 green";
+        assert_eq!(Ok((4, 2, 3)), Game::parse_blocks(sample, sample));
+    }
+
+    #[test]
+    fn parse_out_id_and_max() {
+        let sample = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        assert_eq!(
+            Game {
+                id: 1,
+                red: 4,
+                green: 2,
+                blue: 6
+            },
+            sample.parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn reports_bad_block() {
+        let err = "Game 1: 3 blue, x red".parse::<Game>().unwrap_err();
+        assert_eq!(
+            "line 1, column 17: expected a number of red cubes, found \"x\"",
+            err.to_string()
+        );
+
+        let err = "Game 1: 3 purple".parse::<Game>().unwrap_err();
+        assert_eq!((9, "3 purple"), (err.column, err.snippet.as_str()));
+    }
+}
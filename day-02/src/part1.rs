@@ -0,0 +1,39 @@
+use aoc_common::Answer;
+
+use crate::Game;
+
+const MAX_GAME: Game = Game {id: 0, red: 12, green: 13, blue: 14};
+
+impl Game {
+    fn valid(&self) -> bool {
+        self.red <= MAX_GAME.red && self.green <= MAX_GAME.green && self.blue <= MAX_GAME.blue
+    }
+}
+
+pub fn part1(games: &[Game]) -> Answer {
+    // 12 red cubes, 13 green cubes, and 14 blue cubes
+    games
+        .iter()
+        .filter(|g| g.valid())
+        .map(|g| g.id as u32)
+        .sum::<u32>()
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day02;
+
+    #[test]
+    fn example_input() {
+        let sample = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+        assert_eq!(Answer::Unsigned(8), Day02::solve_part1(sample).unwrap());
+    }
+}
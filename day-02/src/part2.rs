@@ -0,0 +1,49 @@
+use aoc_common::Answer;
+
+use crate::Game;
+
+impl Game {
+    fn power(&self) -> u32 {
+        self.red * self.green * self.blue
+    }
+}
+
+pub fn part2(games: &[Game]) -> Answer {
+    games
+        .iter()
+        .map(|g| g.power())
+        .sum::<u32>()
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::{Day02, Game};
+
+    #[test]
+    fn min_blocks_for_game() {
+        let sample = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        assert_eq!(
+            Game {
+                id: 1,
+                red: 4,
+                green: 2,
+                blue: 6
+            },
+            sample.parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn example_input() {
+        let sample = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+        assert_eq!(Answer::Unsigned(2286), Day02::solve_part2(sample).unwrap());
+    }
+}
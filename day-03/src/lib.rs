@@ -0,0 +1,112 @@
+use std::str::FromStr;
+
+use aoc_common::{grid::parse_tiles, parse::parse_token, Answer, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::part2(parsed)
+    }
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    pub map: String,
+    pub parts: Vec<PartNumber>,
+}
+
+#[derive(Debug)]
+pub struct PartNumber {
+    pub row_idx: usize,
+    pub start_idx: usize,
+    pub end_idx: usize,
+    pub value: u32,
+}
+
+/// Digits, `.` for empty space, and the punctuation that marks a symbol.
+const TILES: &str = "0123456789.!\"#$%&'()*+,-/:;<=>?@[\\]^_`{|}~";
+
+impl FromStr for Schematic {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_tiles(input, TILES)?;
+
+        let mut parts = vec![];
+        for (idx, line) in input.lines().enumerate() {
+            for (start_idx, end_idx) in number_pos(line) {
+                let digits = &line[start_idx..=end_idx];
+                parts.push(PartNumber {
+                    row_idx: idx,
+                    start_idx,
+                    end_idx,
+                    value: parse_token(input, digits, "a part number")?,
+                });
+            }
+        }
+
+        Ok(Schematic {
+            map: input.to_string(),
+            parts,
+        })
+    }
+}
+
+fn number_pos(input: &str) -> Vec<(usize, usize)> {
+    let mut result: Vec<(usize, usize)> = vec![];
+
+    let mut it = input.char_indices();
+
+    while let Some((start_idx, _)) = it.find(|(_, ch)| ch.is_ascii_digit()) {
+        let len = find_len(&input[start_idx..]);
+        let end_idx = start_idx + len;
+        result.push((start_idx, end_idx));
+        for _ in 0..len {
+            it.next();
+        }
+    }
+    result
+}
+
+fn find_len(input: &str) -> usize {
+    input
+        .chars()
+        .take_while(|ch| ch.is_ascii_digit())
+        .collect::<Vec<_>>()
+        .len()
+        - 1
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn find_range_of_digits() {
+        let sample = "..35..633.";
+        assert_eq!(vec![(2, 3), (6, 8)], number_pos(sample));
+    }
+
+    #[test]
+    fn rejects_unknown_tiles() {
+        let err = Day03::parse("467..114..\n...*......\n..35. 633.").unwrap_err();
+        assert_eq!((3, 6, " ".to_string()), (err.line, err.column, err.snippet));
+
+        let err = Day03::parse("467..114..\n..a*......").unwrap_err();
+        assert_eq!((2, 3, "a".to_string()), (err.line, err.column, err.snippet));
+    }
+}
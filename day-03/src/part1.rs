@@ -0,0 +1,63 @@
+use std::cmp::min;
+
+use aoc_common::Answer;
+
+use crate::{PartNumber, Schematic};
+
+impl PartNumber {
+    fn has_adjacent_symbol(&self, map: &str) -> bool {
+        let extended_start = self.start_idx.saturating_sub(1);
+        let extended_end = min(self.end_idx + 1, map.lines().nth(0).unwrap().len() - 1);
+
+        let above = self.row_idx.saturating_sub(1);
+        let below = min(self.row_idx + 1, map.lines().count() - 1);
+
+        [above, self.row_idx, below].iter().any(|r_idx| {
+            map.lines()
+                .nth(*r_idx)
+                .and_then(|line| {
+                    let has_symbol = line[extended_start..=extended_end]
+                        .chars()
+                        .filter(|&ch| !(ch.is_ascii_digit() || ch == '.'))
+                        .count()
+                        > 0;
+                    // dbg!(&line[extended_start..=extended_end], has_symbol);
+                    has_symbol.then_some(())
+                })
+                .is_some()
+        })
+    }
+}
+
+pub fn part1(schematic: &Schematic) -> Answer {
+    schematic
+        .parts
+        .iter()
+        .filter(|p| p.has_adjacent_symbol(&schematic.map))
+        .map(|p| p.value)
+        .sum::<u32>()
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day03;
+
+    #[test]
+    fn example_input() {
+        let sample = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+        assert_eq!(Answer::Unsigned(4361), Day03::solve_part1(sample).unwrap());
+    }
+}
@@ -0,0 +1,68 @@
+use std::cmp::min;
+
+use aoc_common::Answer;
+
+use crate::Schematic;
+
+pub fn part2(schematic: &Schematic) -> Answer {
+    let gears =
+        schematic
+            .map
+            .lines()
+            .enumerate()
+            .fold(vec![], |mut acc: Vec<(usize, usize)>, (row, line)| {
+                for m in line.match_indices('*') {
+                    acc.push((row, m.0));
+                }
+                acc
+            });
+
+    gears
+        .iter()
+        .map(|g| {
+            let gabove = g.0.saturating_sub(1);
+            let gbelow = min(g.0 + 1, schematic.map.lines().count() - 1);
+            let gstart = g.1.saturating_sub(1);
+            let gend = min(g.1 + 1, schematic.map.lines().next().unwrap().len() - 1);
+
+            let attached_parts: Vec<_> = schematic
+                .parts
+                .iter()
+                .filter(|p| (gabove..=gbelow).contains(&p.row_idx))
+                .filter(|p| p.start_idx <= gend && gstart <= p.end_idx)
+                .collect();
+
+            attached_parts
+        })
+        .filter(|p| p.len() == 2)
+        .map(|ps| {
+            ps.iter()
+                .map(|p| p.value)
+                .product::<u32>()
+        })
+        .sum::<u32>()
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day03;
+
+    #[test]
+    fn example_input() {
+        let sample = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+        assert_eq!(Answer::Unsigned(467835), Day03::solve_part2(sample).unwrap());
+    }
+}
@@ -0,0 +1,99 @@
+use std::{collections::HashSet, str::FromStr};
+
+use aoc_common::{
+    parse::{parse_lines, parse_token},
+    Answer, ParseError, Solution,
+};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::part2(parsed)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Card {
+    pub card_id: u8,
+    pub winning: HashSet<u32>,
+    pub numbers: HashSet<u32>,
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (head, rest) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::at_end(line, "':'"))?;
+        let card_id = Card::parse_game_id(line, head)?;
+        let (winning, numbers) = rest
+            .split_once('|')
+            .ok_or_else(|| ParseError::at_end(line, "'|'"))?;
+        Ok(Card {
+            card_id,
+            winning: Card::parse_numbers(line, winning)?,
+            numbers: Card::parse_numbers(line, numbers)?,
+        })
+    }
+}
+
+impl Card {
+    fn parse_game_id(line: &str, head: &str) -> Result<u8, ParseError> {
+        let id = head
+            .strip_prefix("Card")
+            .ok_or_else(|| ParseError::at(line, head, "'Card'"))?;
+        parse_token(line, id.trim(), "a card id")
+    }
+
+    fn parse_numbers(line: &str, value: &str) -> Result<HashSet<u32>, ParseError> {
+        value
+            .split_whitespace()
+            .map(|n| parse_token(line, n, "a number"))
+            .collect()
+    }
+
+    pub fn num_matches(&self) -> usize {
+        self.winning.intersection(&self.numbers).count()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn convert_from_str_to_card() {
+        let sample = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        let winning = [41, 48, 83, 86, 17];
+        let numbers = [83, 86, 6, 31, 17, 9, 48, 53];
+        assert_eq!(
+            Card {
+                card_id: 1u8,
+                winning: winning.into(),
+                numbers: numbers.into()
+            },
+            sample.parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn reports_bad_number() {
+        let err = "Card 1: 41 4x | 83".parse::<Card>().unwrap_err();
+        assert_eq!((1, 12, "4x"), (err.line, err.column, err.snippet.as_str()));
+    }
+}
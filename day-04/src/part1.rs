@@ -0,0 +1,50 @@
+use aoc_common::Answer;
+
+use crate::Card;
+
+impl Card {
+    fn score(&self) -> u32 {
+        match self.num_matches() {
+            0 => 0,
+            matches => 1 << (matches - 1)
+        }
+    }
+}
+
+pub fn part1(cards: &[Card]) -> Answer {
+    cards.iter().map(|c| c.score()).sum::<u32>().into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::{Card, Day04};
+
+    #[test]
+    fn score() {
+        let winning = [41, 48, 83, 86, 17];
+        let numbers = [83, 86, 6, 31, 17, 9, 48, 53];
+        assert_eq!(
+            8,
+            Card {
+                card_id: 1u8,
+                winning: winning.into(),
+                numbers: numbers.into()
+            }
+            .score()
+        );
+    }
+
+    #[test]
+    fn example_input() {
+        let sample = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+        assert_eq!(Answer::Unsigned(13), Day04::solve_part1(sample).unwrap());
+    }
+}
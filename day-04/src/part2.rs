@@ -0,0 +1,38 @@
+use std::{collections::HashMap, iter::repeat};
+
+use aoc_common::Answer;
+
+use crate::Card;
+
+pub fn part2(cards: &[Card]) -> Answer {
+    let card_count: HashMap<u8, u32> = cards.iter().map(|c| c.card_id).zip(repeat(1u32)).collect();
+
+    cards.iter().fold(card_count, |mut acc, card| {
+        let add = *acc.get(&card.card_id).unwrap();
+        for i in 0..card.num_matches() {
+            let copy_id = i as u8 + 1 + card.card_id;
+            acc.entry(copy_id).and_modify(|v| *v += add);
+        }
+        acc
+    }).values().sum::<u32>().into()
+    
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day04;
+
+    #[test]
+    fn example_input() {
+        let sample = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+        assert_eq!(Answer::Unsigned(30), Day04::solve_part2(sample).unwrap());
+    }
+}
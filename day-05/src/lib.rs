@@ -0,0 +1,389 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+    ops::Range,
+    str::FromStr,
+};
+
+use aoc_common::{parse::parse_token, Answer, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+pub mod piecewise;
+
+pub use piecewise::Piecewise;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::part2(parsed)
+    }
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub mappings: Vec<Mapping>,
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<_> = input.lines().collect();
+        let mut section_iter = lines.split(|l| l.is_empty());
+
+        // Process seeds
+        let seeds_line = section_iter
+            .next()
+            .and_then(|s| s.first())
+            .copied()
+            .unwrap_or(input);
+        let seed_tokens: Vec<_> = seeds_line
+            .strip_prefix("seeds: ")
+            .ok_or_else(|| ParseError::at(input, seeds_line, "'seeds: '"))?
+            .split_whitespace()
+            .collect();
+        if seed_tokens.is_empty() {
+            let end = &seeds_line[seeds_line.len()..];
+            return Err(ParseError::at(input, end, "a seed number"));
+        }
+        let seeds = seed_tokens
+            .into_iter()
+            .map(|n| parse_token(input, n, "a seed number"))
+            .collect::<Result<_, _>>()?;
+
+        // Process maps
+        let mappings = section_iter
+            .map(|section| gen_map(input, section))
+            .collect::<Result<_, _>>()?;
+        Ok(Almanac { seeds, mappings })
+    }
+}
+
+impl Almanac {
+    /// The shortest chain of mappings leading from the `from` category to
+    /// the `to` category, e.g. `soil` to `humidity`.
+    pub fn chain(&self, from: &str, to: &str) -> Option<Vec<&Mapping>> {
+        let mut by_source: HashMap<&str, Vec<&Mapping>> = HashMap::new();
+        for mapping in &self.mappings {
+            by_source.entry(&mapping.source).or_default().push(mapping);
+        }
+
+        let mut reached_by: HashMap<&str, &Mapping> = HashMap::new();
+        let mut queue = VecDeque::from([from]);
+        while let Some(category) = queue.pop_front() {
+            if category == to {
+                let mut chain = vec![];
+                let mut category = to;
+                while category != from {
+                    let mapping = reached_by[category];
+                    chain.push(mapping);
+                    category = &mapping.source;
+                }
+                chain.reverse();
+                return Some(chain);
+            }
+            for &mapping in by_source.get(category).into_iter().flatten() {
+                if mapping.target != from && !reached_by.contains_key(mapping.target.as_str()) {
+                    reached_by.insert(&mapping.target, mapping);
+                    queue.push_back(&mapping.target);
+                }
+            }
+        }
+        None
+    }
+
+    /// Every step `id` takes from the `from` category to the `to` category,
+    /// with the range that moved it.
+    pub fn explain(&self, id: u64, from: &str, to: &str) -> Option<Vec<Step<'_>>> {
+        let mut id = id;
+        let steps = self
+            .chain(from, to)?
+            .into_iter()
+            .map(|mapping| {
+                let range = mapping.range_for(id);
+                let step = Step {
+                    mapping,
+                    id,
+                    range,
+                    result: mapping.translate(id),
+                };
+                id = step.result;
+                step
+            })
+            .collect();
+        Some(steps)
+    }
+}
+
+/// `id` passing through `mapping`, moved by `range` or unchanged without one.
+#[derive(Debug)]
+pub struct Step<'a> {
+    pub mapping: &'a Mapping,
+    pub id: u64,
+    pub range: Option<&'a Ranges>,
+    pub result: u64,
+}
+
+impl fmt::Display for Step<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Step {
+            mapping,
+            id,
+            range,
+            result,
+        } = self;
+        write!(f, "{} {id} -> {} {result}", mapping.source, mapping.target)?;
+        match range {
+            Some(r) => write!(
+                f,
+                " (range {} {} {})",
+                r.dest_start, r.source_start, r.range_len
+            ),
+            None => write!(f, " (unmapped)"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Mapping {
+    pub name: String,
+    pub source: String,
+    pub target: String,
+    pub ranges: Vec<Ranges>,
+}
+
+impl Mapping {
+    pub fn translate(&self, id: u64) -> u64 {
+        self.range_for(id).map_or(id, |r| r.destination_for(&id))
+    }
+
+    pub fn range_for(&self, id: u64) -> Option<&Ranges> {
+        self.ranges.iter().find(|r| r.source_contains(&id))
+    }
+
+    /// Maps every id in `ids` at once. Intervals are split where they cross
+    /// the edge of a range, and the parts outside every range keep their ids.
+    pub fn translate_ranges(&self, ids: &[Range<u64>]) -> Vec<Range<u64>> {
+        let mut unmapped: Vec<_> = ids.iter().filter(|r| !r.is_empty()).cloned().collect();
+        let mut mapped = vec![];
+        for range in &self.ranges {
+            let source = range.source_span();
+            let mut rest = vec![];
+            for ids in unmapped {
+                let start = ids.start.max(source.start);
+                let end = ids.end.min(source.end);
+                if start >= end {
+                    rest.push(ids);
+                    continue;
+                }
+                let dest = range.destination_for(&start);
+                mapped.push(dest..dest + (end - start));
+                if ids.start < start {
+                    rest.push(ids.start..start);
+                }
+                if end < ids.end {
+                    rest.push(end..ids.end);
+                }
+            }
+            unmapped = rest;
+        }
+        mapped.extend(unmapped);
+        mapped
+    }
+}
+
+#[derive(Debug)]
+pub struct Ranges {
+    pub source_start: u64,
+    pub dest_start: u64,
+    pub range_len: u64,
+}
+
+impl Ranges {
+    fn source_span(&self) -> Range<u64> {
+        self.source_start..(self.source_start + self.range_len)
+    }
+
+    fn source_contains(&self, id: &u64) -> bool {
+        self.source_span().contains(id)
+    }
+
+    fn destination_for(&self, source_id: &u64) -> u64 {
+        let offset = *source_id - self.source_start;
+        self.dest_start + offset
+    }
+}
+
+fn parse_ranges(input: &str, line: &str) -> Result<Ranges, ParseError> {
+    let mut nums = line.split_whitespace();
+    let mut next = |expected| match nums.next() {
+        Some(n) => parse_token(input, n, expected),
+        None => Err(ParseError::at(input, &line[line.len()..], expected)),
+    };
+    let ranges = Ranges {
+        dest_start: next("a destination start")?,
+        source_start: next("a source start")?,
+        range_len: next("a range length")?,
+    };
+    match nums.next() {
+        Some(extra) => Err(ParseError::at(input, extra, "end of line")),
+        None => Ok(ranges),
+    }
+}
+
+fn gen_map(input: &str, section: &[&str]) -> Result<Mapping, ParseError> {
+    let header = section.first().copied().unwrap_or(input);
+    let name = header
+        .strip_suffix(" map:")
+        .ok_or_else(|| ParseError::at(input, header, "a line ending with ' map:'"))?;
+    let (source, target) = name
+        .split_once("-to-")
+        .ok_or_else(|| ParseError::at(input, name, "a name like 'seed-to-soil'"))?;
+    let ranges = section
+        .iter()
+        .skip(1)
+        .map(|line| parse_ranges(input, line))
+        .collect::<Result<_, _>>()?;
+    Ok(Mapping {
+        name: name.to_string(),
+        source: source.to_string(),
+        target: target.to_string(),
+        ranges,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reports_short_range_line() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50";
+        let err = input.parse::<Almanac>().unwrap_err();
+        assert_eq!(
+            "line 5, column 6: expected a range length, found end of line",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn requires_seeds() {
+        let err = "seeds:\n\nseed-to-soil map:\n50 98 2"
+            .parse::<Almanac>()
+            .unwrap_err();
+        assert_eq!(
+            "line 1, column 1: expected 'seeds: ', found \"seeds:\"",
+            err.to_string()
+        );
+
+        let err = "seeds: \n\nseed-to-soil map:\n50 98 2"
+            .parse::<Almanac>()
+            .unwrap_err();
+        assert_eq!(
+            "line 1, column 8: expected a seed number, found end of line",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn splits_ranges_at_boundaries() {
+        let input = "seeds: 0\n\nseed-to-soil map:\n50 98 2\n52 50 48";
+        let almanac: Almanac = input.parse().unwrap();
+
+        let mut mapped = almanac.mappings[0].translate_ranges(&[79..93, 40..60, 95..101, 7..7]);
+        mapped.sort_by_key(|r| r.start);
+        assert_eq!(
+            vec![40..50, 50..52, 52..62, 81..95, 97..100, 100..101],
+            mapped
+        );
+    }
+
+    #[test]
+    fn chains_by_category() {
+        let almanac: Almanac = SAMPLE.parse().unwrap();
+
+        let names = |chain: Vec<&Mapping>| chain.iter().map(|m| m.name.clone()).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "soil-to-fertilizer",
+                "fertilizer-to-water",
+                "water-to-light",
+                "light-to-temperature",
+                "temperature-to-humidity",
+            ],
+            names(almanac.chain("soil", "humidity").unwrap())
+        );
+        assert!(almanac.chain("seed", "seed").unwrap().is_empty());
+        assert!(almanac.chain("humidity", "soil").is_none());
+        assert!(almanac.chain("seed", "rocket").is_none());
+    }
+
+    #[test]
+    fn explains_a_seed() {
+        let almanac: Almanac = SAMPLE.parse().unwrap();
+        let steps = almanac.explain(79, "seed", "location").unwrap();
+
+        let ids: Vec<_> = steps.iter().map(|s| s.result).collect();
+        assert_eq!(vec![81, 81, 81, 74, 78, 78, 82], ids);
+        assert_eq!("seed 79 -> soil 81 (range 52 50 48)", steps[0].to_string());
+        assert_eq!("soil 81 -> fertilizer 81 (unmapped)", steps[1].to_string());
+    }
+
+    #[test]
+    fn requires_category_names() {
+        let err = "seeds: 1\n\nseed-soil map:\n1 2 3"
+            .parse::<Almanac>()
+            .unwrap_err();
+        assert_eq!(
+            "line 3, column 1: expected a name like 'seed-to-soil', found \"seed-soil\"",
+            err.to_string()
+        );
+    }
+
+    pub(crate) const SAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+}
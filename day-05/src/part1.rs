@@ -0,0 +1,63 @@
+use aoc_common::Answer;
+
+use crate::{Almanac, Piecewise};
+
+pub fn part1(almanac: &Almanac) -> Answer {
+    let Some(chain) = almanac.chain("seed", "location") else {
+        return Answer::Unsolved("no mappings lead from seed to location".to_string());
+    };
+    let seed_to_location = Piecewise::compose(chain);
+    almanac
+        .seeds
+        .iter()
+        .map(|&s| seed_to_location.apply(s))
+        .min()
+        .ok_or("the almanac lists no seeds")
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day05;
+
+    #[test]
+    fn example_input() {
+        let sample = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+        assert_eq!(Answer::Unsigned(35), Day05::solve_part1(sample).unwrap());
+    }
+}
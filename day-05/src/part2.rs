@@ -0,0 +1,86 @@
+use aoc_common::Answer;
+use itertools::Itertools;
+
+use crate::Almanac;
+
+pub fn part2(almanac: &Almanac) -> Answer {
+    // The seeds are pairs of range start and length.
+    if almanac.seeds.len() % 2 == 1 {
+        let last = almanac.seeds[almanac.seeds.len() - 1];
+        return Answer::Unsolved(format!("seed {last} has no range length"));
+    }
+    let seeds: Vec<_> = almanac
+        .seeds
+        .iter()
+        .tuples::<(&u64, &u64)>()
+        .map(|(&start, &len)| start..(start + len))
+        .collect();
+
+    let Some(chain) = almanac.chain("seed", "location") else {
+        return Answer::Unsolved("no mappings lead from seed to location".to_string());
+    };
+    chain
+        .into_iter()
+        .fold(seeds, |ids, map| map.translate_ranges(&ids))
+        .iter()
+        .map(|r| r.start)
+        .min()
+        .ok_or("every seed range is empty")
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day05;
+
+    #[test]
+    fn example_input() {
+        let sample = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+        assert_eq!(Answer::Unsigned(46), Day05::solve_part2(sample).unwrap());
+    }
+
+    #[test]
+    fn unpaired_seed_is_unsolved() {
+        let sample = "seeds: 79 14 55\n\nseed-to-location map:\n50 98 2";
+        assert_eq!(Answer::Unsigned(14), Day05::solve_part1(sample).unwrap());
+        assert_eq!(
+            Answer::Unsolved("seed 55 has no range length".to_string()),
+            Day05::solve_part2(sample).unwrap()
+        );
+    }
+}
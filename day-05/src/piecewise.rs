@@ -0,0 +1,194 @@
+use std::ops::Range;
+
+use crate::Mapping;
+
+/// A piece of a [`Piecewise`] map: ids in `source` are shifted so that
+/// `source.start` lands on `dest_start`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Piece {
+    pub source: Range<u64>,
+    pub dest_start: u64,
+}
+
+impl Piece {
+    fn dest(&self) -> Range<u64> {
+        self.dest_start..self.dest_start + (self.source.end - self.source.start)
+    }
+
+    fn apply(&self, id: u64) -> u64 {
+        self.dest_start + (id - self.source.start)
+    }
+}
+
+/// A map over every id below `u64::MAX`, stored as sorted, contiguous pieces
+/// with adjacent pieces of the same shift merged. Ids outside a [`Mapping`]'s
+/// ranges get identity pieces, so any chain of mappings collapses into one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Piecewise {
+    pieces: Vec<Piece>,
+}
+
+impl Piecewise {
+    pub fn identity() -> Self {
+        Self::from_pieces(vec![Piece {
+            source: 0..u64::MAX,
+            dest_start: 0,
+        }])
+    }
+
+    /// `mappings` applied in order, as a single map.
+    pub fn compose<'a>(mappings: impl IntoIterator<Item = &'a Mapping>) -> Self {
+        mappings
+            .into_iter()
+            .fold(Self::identity(), |acc, mapping| acc.then(&mapping.into()))
+    }
+
+    pub fn pieces(&self) -> &[Piece] {
+        &self.pieces
+    }
+
+    pub fn apply(&self, id: u64) -> u64 {
+        let idx = self.pieces.partition_point(|p| p.source.end <= id);
+        self.pieces.get(idx).map_or(id, |p| p.apply(id))
+    }
+
+    /// This map followed by `next`.
+    pub fn then(&self, next: &Piecewise) -> Self {
+        let mut pieces = vec![];
+        for piece in &self.pieces {
+            let dest = piece.dest();
+            let mut idx = next.pieces.partition_point(|p| p.source.end <= dest.start);
+            let mut start = dest.start;
+            while start < dest.end {
+                let Some(outer) = next.pieces.get(idx) else {
+                    break;
+                };
+                let end = dest.end.min(outer.source.end);
+                let offset = start - dest.start;
+                pieces.push(Piece {
+                    source: piece.source.start + offset
+                        ..piece.source.start + offset + (end - start),
+                    dest_start: outer.apply(start),
+                });
+                start = end;
+                idx += 1;
+            }
+        }
+        Self::from_pieces(pieces)
+    }
+
+    /// The map taking every destination id back to its source, if no two ids
+    /// share a destination.
+    pub fn inverse(&self) -> Option<Self> {
+        let mut pieces: Vec<_> = self
+            .pieces
+            .iter()
+            .map(|p| Piece {
+                source: p.dest(),
+                dest_start: p.source.start,
+            })
+            .collect();
+        pieces.sort_by_key(|p| p.source.start);
+        // The sources cover every id, so the destinations do too exactly
+        // when they do not overlap.
+        let contiguous = pieces
+            .windows(2)
+            .all(|w| w[0].source.end == w[1].source.start);
+        contiguous.then(|| Self::from_pieces(pieces))
+    }
+
+    fn from_pieces(pieces: Vec<Piece>) -> Self {
+        let mut merged: Vec<Piece> = Vec::with_capacity(pieces.len());
+        for piece in pieces.into_iter().filter(|p| !p.source.is_empty()) {
+            match merged.last_mut() {
+                Some(last)
+                    if last.source.end == piece.source.start
+                        && last.dest().end == piece.dest_start =>
+                {
+                    last.source.end = piece.source.end;
+                }
+                _ => merged.push(piece),
+            }
+        }
+        Self { pieces: merged }
+    }
+}
+
+impl From<&Mapping> for Piecewise {
+    fn from(mapping: &Mapping) -> Self {
+        let mut ranges: Vec<_> = mapping.ranges.iter().collect();
+        ranges.sort_by_key(|r| r.source_start);
+
+        let mut pieces = vec![];
+        let mut next_id = 0;
+        for range in ranges {
+            let start = range.source_start.max(next_id);
+            let end = range.source_start + range.range_len;
+            if start >= end {
+                // Overlaps an earlier range, which `Mapping::translate` prefers.
+                continue;
+            }
+            pieces.push(Piece {
+                source: next_id..start,
+                dest_start: next_id,
+            });
+            pieces.push(Piece {
+                source: start..end,
+                dest_start: range.dest_start + (start - range.source_start),
+            });
+            next_id = end;
+        }
+        pieces.push(Piece {
+            source: next_id..u64::MAX,
+            dest_start: next_id,
+        });
+        Self::from_pieces(pieces)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::SAMPLE, Almanac};
+
+    #[test]
+    fn composes_like_applying_in_turn() {
+        let almanac: Almanac = SAMPLE.parse().unwrap();
+        let composed = Piecewise::compose(&almanac.mappings);
+
+        for id in 0..200 {
+            let expected = almanac.mappings.iter().fold(id, |acc, m| m.translate(acc));
+            assert_eq!(expected, composed.apply(id), "id {id}");
+        }
+        let pieces = composed.pieces();
+        assert!(pieces
+            .windows(2)
+            .all(|w| w[0].source.end == w[1].source.start));
+        assert_eq!(
+            0..u64::MAX,
+            pieces[0].source.start..pieces[pieces.len() - 1].source.end
+        );
+    }
+
+    #[test]
+    fn inverts_bijections() {
+        let almanac: Almanac = SAMPLE.parse().unwrap();
+        let composed = Piecewise::compose(&almanac.mappings);
+        let inverse = composed.inverse().expect("the sample maps are bijective");
+
+        for id in 0..200 {
+            assert_eq!(id, inverse.apply(composed.apply(id)));
+        }
+        assert_eq!(Piecewise::identity(), composed.then(&inverse));
+    }
+
+    #[test]
+    fn no_inverse_when_ids_collide() {
+        let almanac: Almanac = "seeds: 1\n\na-to-b map:\n0 10 5".parse().unwrap();
+        let piecewise = Piecewise::from(&almanac.mappings[0]);
+
+        assert_eq!(3, piecewise.apply(13));
+        assert_eq!(3, piecewise.apply(3));
+        assert_eq!(None, piecewise.inverse());
+    }
+}
@@ -0,0 +1,217 @@
+use std::ops::RangeInclusive;
+
+use aoc_common::{parse::parse_token, Answer, ParseError, Solution};
+use itertools::Itertools;
+use num_bigint::BigUint;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = Vec<Race>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let times = parse_row(input, 0, "Time:")?;
+        let distances = parse_row(input, 1, "Distance:")?;
+        let number = |n| parse_token(input, n, "a number");
+        let races = times
+            .iter()
+            .zip(&distances)
+            .map(|(&time, &max_distance)| {
+                Ok(Race {
+                    time: number(time)?,
+                    max_distance: number(max_distance)?,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        if let Some(&time) = times.get(distances.len()) {
+            let expected = "a time with a distance below it";
+            return Err(ParseError::at(input, time, expected));
+        }
+        if let Some(&distance) = distances.get(times.len()) {
+            let expected = "a distance with a time above it";
+            return Err(ParseError::at(input, distance, expected));
+        }
+        Ok(races)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::part2(parsed)
+    }
+}
+
+/// The numbers on `row`, which must start with `label` and list at least one.
+fn parse_row<'a>(input: &'a str, row: usize, label: &str) -> Result<Vec<&'a str>, ParseError> {
+    let expected = format!("a line starting with '{label}'");
+    let line = input
+        .lines()
+        .nth(row)
+        .ok_or_else(|| ParseError::at_end(input, &expected))?;
+
+    let numbers = line
+        .strip_prefix(label)
+        .ok_or_else(|| ParseError::at(input, line, &expected))?
+        .split_whitespace()
+        .collect_vec();
+    if numbers.is_empty() {
+        return Err(ParseError::at(input, &line[line.len()..], "a number"));
+    }
+    Ok(numbers)
+}
+
+/// A race of `time` milliseconds with the record `max_distance`. Both are
+/// arbitrary precision, so kerned sheets of any length still fit.
+#[derive(Debug, PartialEq)]
+pub struct Race {
+    pub time: BigUint,
+    pub max_distance: BigUint,
+}
+
+impl From<(u64, u64)> for Race {
+    fn from(value: (u64, u64)) -> Self {
+        Race {
+            time: value.0.into(),
+            max_distance: value.1.into(),
+        }
+    }
+}
+
+impl Race {
+    /// Reads the sheet's columns as one race, ignoring the spaces between them.
+    ///
+    /// Panics if `races` is empty, which `Day06::parse` never returns as it
+    /// rejects rows without numbers.
+    pub fn kerned(races: &[Race]) -> Race {
+        let kern = |nums: Vec<&BigUint>| {
+            nums.iter()
+                .join("")
+                .parse()
+                .expect("a sheet has at least one race")
+        };
+        Race {
+            time: kern(races.iter().map(|r| &r.time).collect()),
+            max_distance: kern(races.iter().map(|r| &r.max_distance).collect()),
+        }
+    }
+
+    /// The hold times that beat the record, i.e. the integers `t` with
+    /// `t * (time - t) > max_distance`.
+    ///
+    /// The winners are symmetric around `time / 2`, so only the first one is
+    /// searched for. It lies just above the smaller root of the quadratic,
+    /// `(time - sqrt(time² - 4 * max_distance)) / 2`, which is estimated
+    /// with an integer square root and then nudged onto the exact boundary.
+    pub fn holds_that_win(&self) -> Option<RangeInclusive<BigUint>> {
+        let time = &self.time;
+        let four_d = &self.max_distance * 4u8;
+        let squared = time * time;
+        if squared <= four_d {
+            return None;
+        }
+
+        let one = BigUint::from(1u8);
+        let mut first = (time - (squared - four_d).sqrt()) / 2u8;
+        while first > BigUint::ZERO && self.wins(&(&first - &one)) {
+            first -= &one;
+        }
+        while !self.wins(&first) {
+            if &first * 2u8 >= *time {
+                return None;
+            }
+            first += &one;
+        }
+        let last = time - &first;
+        Some(first..=last)
+    }
+
+    /// How many hold times beat the record.
+    pub fn ways_to_win(&self) -> BigUint {
+        self.holds_that_win()
+            .map_or(BigUint::ZERO, |r| r.end() - r.start() + 1u8)
+    }
+
+    fn wins(&self, held: &BigUint) -> bool {
+        held <= &self.time && self.distance_if_held(held) > self.max_distance
+    }
+
+    fn distance_if_held(&self, held: &BigUint) -> BigUint {
+        (&self.time - held) * held
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rejects_unmatched_columns() {
+        let err = Day06::parse("Time: 7 15\nDistance: 9").unwrap_err();
+        assert_eq!(
+            "line 1, column 9: expected a time with a distance below it, found \"15\"",
+            err.to_string()
+        );
+
+        let err = Day06::parse("Time: 7\nDistance: 9 40").unwrap_err();
+        assert_eq!((2, 13, "40"), (err.line, err.column, err.snippet.as_str()));
+
+        let err = Day06::parse("Time:\nDistance:").unwrap_err();
+        assert_eq!(
+            "line 1, column 6: expected a number, found end of line",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn kerns_races() {
+        let races = [Race::from((7, 9)), Race::from((15, 40)), Race::from((30, 200))];
+        assert_eq!(Race::from((71530, 940200)), Race::kerned(&races));
+    }
+
+    #[test]
+    fn boundaries_are_exact() {
+        // Holding for 10 or 20 only ties the record.
+        let race = Race::from((30, 200));
+        assert_eq!(Some(11u8.into()..=19u8.into()), race.holds_that_win());
+
+        assert_eq!(None, Race::from((4, 4)).holds_that_win());
+        assert_eq!(BigUint::ZERO, Race::from((0, 0)).ways_to_win());
+        assert_eq!(BigUint::from(1u8), Race::from((2, 0)).ways_to_win());
+    }
+
+    #[test]
+    fn matches_a_scan() {
+        for time in 0u64..60 {
+            for max_distance in 0u64..(time * time / 4 + 3) {
+                let race = Race::from((time, max_distance));
+                let scanned = (0..=time).filter(|t| t * (time - t) > max_distance).count();
+                assert_eq!(BigUint::from(scanned), race.ways_to_win(), "{race:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn handles_huge_races() {
+        let time: BigUint = "1".repeat(40).parse().unwrap();
+        let race = Race {
+            time: time.clone(),
+            max_distance: BigUint::ZERO,
+        };
+        assert_eq!(time - 1u8, race.ways_to_win());
+
+        // Just under the best possible distance, so only the middle wins.
+        let time = BigUint::from(10u8).pow(30);
+        let best = (&time / 2u8) * (&time / 2u8);
+        let race = Race {
+            time: time.clone(),
+            max_distance: best - 1u8,
+        };
+        assert_eq!(BigUint::from(1u8), race.ways_to_win());
+    }
+}
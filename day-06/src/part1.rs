@@ -0,0 +1,27 @@
+use aoc_common::Answer;
+use num_bigint::BigUint;
+
+use crate::Race;
+
+pub fn part1(races: &[Race]) -> Answer {
+    races
+        .iter()
+        .map(Race::ways_to_win)
+        .product::<BigUint>()
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day06;
+
+    #[test]
+    fn example_part1() {
+        let sample = "Time:      7  15   30
+Distance:  9  40  200";
+
+        assert_eq!(Answer::Unsigned(288), Day06::solve_part1(sample).unwrap());
+    }
+}
@@ -0,0 +1,22 @@
+use aoc_common::Answer;
+
+use crate::Race;
+
+pub fn part2(races: &[Race]) -> Answer {
+    Race::kerned(races).ways_to_win().into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day06;
+
+    #[test]
+    fn example_part2() {
+        let sample = "Time:      7  15   30
+Distance:  9  40  200";
+
+        assert_eq!(Answer::Unsigned(71503), Day06::solve_part2(sample).unwrap());
+    }
+}
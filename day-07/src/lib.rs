@@ -0,0 +1,258 @@
+use std::str::FromStr;
+
+use aoc_common::{
+    parse::{parse_lines, parse_token},
+    Answer, ParseError, Solution,
+};
+use itertools::Itertools;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::part2(parsed)
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, PartialOrd, Ord, Clone, Copy)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfKind,
+    FullHouse,
+    FourOfKind,
+    FiveOfKind,
+}
+
+impl HandType {
+    /// Classifies a hand from the sizes of its groups of matching cards,
+    /// after the `wild` cards join the largest group. Hands of any size get
+    /// the best shape they contain, so six of a kind is still five of a kind.
+    pub fn classify(mut groups: Vec<usize>, wild: usize) -> Self {
+        groups.sort_unstable_by(|a, b| b.cmp(a));
+        match groups.first_mut() {
+            Some(largest) => *largest += wild,
+            None => groups.push(wild),
+        }
+
+        match (groups[0], groups.get(1).copied().unwrap_or(0)) {
+            (5.., _) => HandType::FiveOfKind,
+            (4, _) => HandType::FourOfKind,
+            (3, 2..) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfKind,
+            (2, 2..) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Hand {
+    pub bid: u32,
+    pub cards: String,
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        Ruleset::STANDARD.parse_hand(line)
+    }
+}
+
+/// How hands are read and ranked: the card ranks from weakest to strongest,
+/// which of them are wild and how many cards make a hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ruleset<'a> {
+    pub order: &'a str,
+    pub wild: &'a str,
+    pub hand_size: usize,
+}
+
+impl Ruleset<'static> {
+    pub const STANDARD: Self = Ruleset {
+        order: "23456789TJQKA",
+        wild: "",
+        hand_size: 5,
+    };
+
+    /// Jacks become jokers: the weakest card, but wild.
+    pub const JOKERS: Self = Ruleset {
+        order: "J23456789TQKA",
+        wild: "J",
+        hand_size: 5,
+    };
+}
+
+impl Ruleset<'_> {
+    pub fn parse_hand(&self, line: &str) -> Result<Hand, ParseError> {
+        let expected = format!("{} cards", self.hand_size);
+        let mut it = line.split_whitespace();
+        let cards = it
+            .next()
+            .ok_or_else(|| ParseError::at_end(line, &expected))?;
+        let bid = it.next().ok_or_else(|| ParseError::at_end(line, "a bid"))?;
+        if let Some(extra) = it.next() {
+            return Err(ParseError::at(line, extra, "end of line"));
+        }
+
+        let bid = parse_token(line, bid, "a bid")?;
+        if let Some((idx, ch)) = cards
+            .char_indices()
+            .find(|(_, ch)| !self.order.contains(*ch))
+        {
+            return Err(ParseError::at(
+                line,
+                &cards[idx..idx + ch.len_utf8()],
+                "a card rank",
+            ));
+        }
+        if cards.chars().count() != self.hand_size {
+            return Err(ParseError::at(line, cards, expected));
+        }
+
+        Ok(Hand {
+            bid,
+            cards: cards.to_string(),
+        })
+    }
+
+    pub fn hand_type(&self, hand: &Hand) -> HandType {
+        let wild = hand
+            .cards
+            .chars()
+            .filter(|ch| self.wild.contains(*ch))
+            .count();
+        let groups = hand
+            .cards
+            .chars()
+            .filter(|ch| !self.wild.contains(*ch))
+            .counts()
+            .into_values()
+            .collect();
+        HandType::classify(groups, wild)
+    }
+
+    /// Orders hands by type, then card by card.
+    pub fn strength(&self, hand: &Hand) -> (HandType, Vec<usize>) {
+        let cards = hand
+            .cards
+            .chars()
+            .map(|ch| self.order.find(ch).map_or(0, |idx| idx + 1))
+            .collect();
+        (self.hand_type(hand), cards)
+    }
+
+    pub fn total_winnings(&self, hands: &[Hand]) -> u64 {
+        hands
+            .iter()
+            .sorted_by_cached_key(|hand| self.strength(hand))
+            .enumerate()
+            .map(|(idx, hand)| u64::from(hand.bid) * (idx as u64 + 1))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_str_to_hand() {
+        let sample = "32T3K 765";
+        let hand = Hand {
+            bid: 765,
+            cards: "32T3K".to_string(),
+        };
+        assert_eq!(Ok(hand.clone()), sample.parse());
+        assert_eq!(HandType::OnePair, Ruleset::STANDARD.hand_type(&hand));
+    }
+
+    #[test]
+    fn rank_cards_equal_handtypes() {
+        let full_eight = "77888 0".parse::<Hand>().unwrap();
+        let full_seven = "77788 0".parse::<Hand>().unwrap();
+
+        let strength = |hand| Ruleset::STANDARD.strength(hand);
+        assert!(strength(&full_seven) < strength(&full_eight));
+    }
+
+    #[test]
+    fn rejects_bad_hands() {
+        let err = "32X3K 765".parse::<Hand>().unwrap_err();
+        assert_eq!((3, "X"), (err.column, err.snippet.as_str()));
+
+        let err = "32T3 765".parse::<Hand>().unwrap_err();
+        assert_eq!(
+            "line 1, column 1: expected 5 cards, found \"32T3\"",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn wild_cards_join_the_largest_group() {
+        let hand_type = |cards: &str| {
+            let hand = Hand {
+                bid: 0,
+                cards: cards.to_string(),
+            };
+            Ruleset::JOKERS.hand_type(&hand)
+        };
+
+        assert_eq!(HandType::OnePair, hand_type("32T3K"));
+        assert_eq!(HandType::FourOfKind, hand_type("T55J5"));
+        assert_eq!(HandType::FourOfKind, hand_type("KTJJT"));
+        assert_eq!(HandType::FullHouse, hand_type("2233J"));
+        assert_eq!(HandType::ThreeOfKind, hand_type("234JJ"));
+        assert_eq!(HandType::OnePair, hand_type("2345J"));
+        assert_eq!(HandType::FiveOfKind, hand_type("JJJJJ"));
+    }
+
+    #[test]
+    fn other_hand_sizes() {
+        let four = Ruleset {
+            hand_size: 4,
+            ..Ruleset::JOKERS
+        };
+        let six = Ruleset {
+            hand_size: 6,
+            ..Ruleset::STANDARD
+        };
+
+        let hand = four.parse_hand("J2J2 7").unwrap();
+        assert_eq!(HandType::FourOfKind, four.hand_type(&hand));
+        assert!(four.parse_hand("J2J22 7").is_err());
+
+        let classify = |line| six.hand_type(&six.parse_hand(line).unwrap());
+        assert_eq!(HandType::FiveOfKind, classify("AAAAAA 1"));
+        assert_eq!(HandType::FullHouse, classify("222333 1"));
+        assert_eq!(HandType::TwoPair, classify("223344 1"));
+        assert_eq!(HandType::HighCard, classify("23456A 1"));
+    }
+
+    #[test]
+    fn custom_order() {
+        let aces_low = Ruleset {
+            order: "A23456789TJQK",
+            ..Ruleset::STANDARD
+        };
+        let low = aces_low.parse_hand("A2345 1").unwrap();
+        let high = aces_low.parse_hand("K2345 1").unwrap();
+        assert!(aces_low.strength(&low) < aces_low.strength(&high));
+    }
+}
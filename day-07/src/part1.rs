@@ -0,0 +1,25 @@
+use aoc_common::Answer;
+
+use crate::{Hand, Ruleset};
+
+pub fn part1(hands: &[Hand]) -> Answer {
+    Ruleset::STANDARD.total_winnings(hands).into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day07;
+
+    #[test]
+    fn example_part1() {
+        let sample = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+        assert_eq!(Answer::Unsigned(6440), Day07::solve_part1(sample).unwrap());
+    }
+}
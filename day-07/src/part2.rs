@@ -0,0 +1,25 @@
+use aoc_common::Answer;
+
+use crate::{Hand, Ruleset};
+
+pub fn part2(hands: &[Hand]) -> Answer {
+    Ruleset::JOKERS.total_winnings(hands).into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day07;
+
+    #[test]
+    fn example_part2() {
+        let sample = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+        assert_eq!(Answer::Unsigned(5905), Day07::solve_part2(sample).unwrap());
+    }
+}
@@ -1,93 +1,41 @@
-use std::{collections::BTreeMap, ops::ControlFlow};
-
-use itertools::Itertools;
+use aoc_common::{
+    input::{exit_with_error, InputArgs},
+    Solution,
+};
+use clap::Parser;
+use day_08::{part1::walk, Day08, Pattern};
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+/// Counts the steps from a start node to an end node.
+#[derive(Parser, Debug)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+    /// Nodes to start from, `*` matches any characters.
+    #[arg(long, default_value = "AAA")]
+    start: Pattern,
+    /// Nodes to stop at, `*` matches any characters.
+    #[arg(long, default_value = "ZZZ")]
+    end: Pattern,
+}
 
 fn main() {
-    let input = include_str!("./input1.txt");
-    let output = part1(input);
+    #[cfg(feature = "dhat-heap")]
+    let _profiler = dhat::Profiler::builder()
+        .file_name(aoc_common::profile::report_path(8, "part1-heap.json"))
+        .build();
+    #[cfg(all(feature = "dhat-ad-hoc", not(feature = "dhat-heap")))]
+    let _profiler = dhat::Profiler::builder()
+        .ad_hoc()
+        .file_name(aoc_common::profile::report_path(8, "part1-ad-hoc.json"))
+        .build();
+
+    let args = Args::parse();
+    let input = args.input.load(8).unwrap_or_else(|e| exit_with_error(e));
+    let network = Day08::parse(&input).unwrap_or_else(|e| exit_with_error(e));
+    let output = walk(&network, &args.start, &args.end).unwrap_or_else(|e| exit_with_error(e));
     dbg!(output);
 }
-
-fn part1(input: &str) -> String {
-    let mut it = input.lines();
-
-    let directions = it.next().expect("directions on the first line");
-
-    let map = it.skip_while(|l| l.is_empty()).map(parse_map_line).fold(
-        BTreeMap::<&str, (&str, &str)>::new(),
-        |mut acc, values| {
-            acc.insert(values.0, values.1);
-            acc
-        },
-    );  
-
-    let start = "AAA";
-    let end = "ZZZ";
-
-    let result = directions.chars().cycle().enumerate().try_fold((start, 0), |acc, (idx, dir)| {
-        if acc.0 == end {
-            return ControlFlow::Break((acc.0, idx));
-        }
-
-        let next = match dir {
-            'L' => map.get(acc.0).expect("Next value must exist.").0,
-            'R' => map.get(acc.0).expect("Next value must exist.").1,
-            _ => panic!("There are no directions besides L or R.")
-        };
-
-        ControlFlow::Continue((next, idx))
-    });
-
-    match result {
-        ControlFlow::Break((_, steps)) => steps.to_string(),
-        _ => panic!("Should not be unreachable.")
-    }
-}
-
-// really should have used nom on this one.
-fn parse_map_line(map_line: &str) -> (&str, (&str, &str)) {
-    let mut it = map_line.split("=");
-    let key = it.next().expect("parse valid key").trim();
-    let left_right = it
-        .next()
-        .and_then(|l| l.trim().split(", ").collect_tuple())
-        .map(|(l, r)| {
-            (
-                l.strip_prefix('(')
-                    .expect("There is a ( that needs to be stripped."),
-                r.strip_suffix(')')
-                    .expect("There is a ) that needs to be stripped."),
-            )
-        })
-        .expect("parse left right");
-    (key, left_right)
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    #[test]
-    fn cycles_counts_steps() {
-        let sample = "LLR
-
-AAA = (BBB, BBB)
-BBB = (AAA, ZZZ)
-ZZZ = (ZZZ, ZZZ)";
-        assert_eq!("6", part1(sample));
-    }
-
-    #[test]
-    fn example_part1() {
-        let sample = "RL
-
-AAA = (BBB, CCC)
-BBB = (DDD, EEE)
-CCC = (ZZZ, GGG)
-DDD = (DDD, DDD)
-EEE = (EEE, EEE)
-GGG = (GGG, GGG)
-ZZZ = (ZZZ, ZZZ)";
-
-        assert_eq!("2", part1(sample));
-    }
-}
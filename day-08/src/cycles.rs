@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use num_integer::{ExtendedGcd, Integer};
+
+use crate::{Network, Pattern};
+
+/// When one ghost stands on an end node: at each step in `prefix`, before it
+/// settles into its cycle at `start`, then at each step in `hits` and every
+/// `length` steps after.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: Vec<u64>,
+    pub start: u64,
+    pub length: u64,
+    pub hits: Vec<u64>,
+}
+
+impl Cycle {
+    pub fn is_end_at(&self, step: u64) -> bool {
+        if step < self.start {
+            return self.prefix.contains(&step);
+        }
+        let offset = (step - self.start) % self.length;
+        self.hits.contains(&(self.start + offset))
+    }
+}
+
+impl Network {
+    /// Walks from `start` until a (node, direction index) pair repeats, which
+    /// pins down the whole infinite walk.
+    pub fn cycle(&self, start: &str, is_end: impl Fn(&str) -> bool) -> Cycle {
+        let directions: Vec<char> = self.directions.chars().collect();
+        let mut seen: HashMap<(&str, usize), u64> = HashMap::new();
+        let mut ends = vec![];
+        let mut node = start;
+        let mut step = 0;
+        loop {
+            let idx = step as usize % directions.len();
+            if let Some(&first) = seen.get(&(node, idx)) {
+                let (prefix, hits) = ends.into_iter().partition(|&s| s < first);
+                return Cycle {
+                    prefix,
+                    start: first,
+                    length: step - first,
+                    hits,
+                };
+            }
+            seen.insert((node, idx), step);
+            if is_end(node) {
+                ends.push(step);
+            }
+            node = self.next(node, directions[idx]);
+            step += 1;
+        }
+    }
+
+    /// The cycle of every ghost, starting on each node matching `start` and
+    /// ending on nodes matching `end`.
+    pub fn ghost_cycles(&self, start: &Pattern, end: &Pattern) -> Vec<Cycle> {
+        self.starts(|node| start.matches(node))
+            .map(|node| self.cycle(node, |node| end.matches(node)))
+            .collect()
+    }
+}
+
+/// The first step at which every ghost is on an end node.
+pub fn first_meeting(cycles: &[Cycle]) -> Option<u64> {
+    // Until every ghost is in its cycle, the latest to settle can only be
+    // on an end node at one of its prefix steps.
+    let settled = cycles.iter().map(|c| c.start).max()?;
+    let early = cycles
+        .iter()
+        .flat_map(|c| c.prefix.iter().copied())
+        .filter(|&step| step < settled)
+        .sorted()
+        .find(|&step| cycles.iter().all(|c| c.is_end_at(step)));
+    if early.is_some() {
+        return early;
+    }
+
+    cycles
+        .iter()
+        .map(|c| c.hits.iter().map(|&hit| (hit as i128, c.length as i128)))
+        .multi_cartesian_product()
+        .filter_map(|congruences| congruences.into_iter().try_fold((0, 1), crt))
+        .map(|(residue, modulus)| {
+            let settled = settled as i128;
+            let behind = (settled - residue).max(0);
+            residue + (behind + modulus - 1) / modulus * modulus
+        })
+        .min()
+        .map(|step| step as u64)
+}
+
+/// Whether the usual shortcut is valid: each ghost reaches an end once per
+/// cycle, first after exactly one cycle length, so the answer is the LCM of
+/// the lengths.
+pub fn lcm_shortcut_holds(cycles: &[Cycle]) -> bool {
+    cycles
+        .iter()
+        .all(|c| c.prefix.is_empty() && c.hits == [c.length])
+}
+
+/// Solves `x ≡ a (mod m)` and `x ≡ b (mod n)` together as `x ≡ r (mod
+/// lcm(m, n))`, for moduli that need not be coprime. `None` when the two
+/// can never agree.
+fn crt((a, m): (i128, i128), (b, n): (i128, i128)) -> Option<(i128, i128)> {
+    let ExtendedGcd { gcd, x, .. } = m.extended_gcd(&n);
+    if (b - a) % gcd != 0 {
+        return None;
+    }
+    let lcm = m / gcd * n;
+    // `x` inverts m / gcd modulo n / gcd.
+    let k = ((b - a) / gcd * x).rem_euclid(n / gcd);
+    Some(((a + m * k).rem_euclid(lcm), lcm))
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Day08;
+
+    #[test]
+    fn combines_congruences() {
+        assert_eq!(Some((4, 6)), crt((0, 2), (1, 3)));
+        assert_eq!(Some((10, 12)), crt((4, 6), (2, 4)));
+        assert_eq!(None, crt((0, 4), (1, 6)));
+    }
+
+    #[test]
+    fn finds_cycles() {
+        let network = Day08::parse(
+            "L
+
+1A = (1B, 1B)
+1B = (1Z, 1Z)
+1Z = (1B, 1B)",
+        )
+        .unwrap();
+
+        let expected = Cycle {
+            prefix: vec![],
+            start: 1,
+            length: 2,
+            hits: vec![2],
+        };
+        assert_eq!(
+            vec![expected],
+            network.ghost_cycles(&"*A".into(), &"*Z".into())
+        );
+    }
+
+    #[test]
+    fn meets_without_the_lcm_shortcut() {
+        // The first ends are at steps 2 and 1, but the second ghost then
+        // takes 3 steps to come back, so they first meet at step 4.
+        let network = Day08::parse(
+            "L
+
+1A = (1B, 1B)
+1B = (1Z, 1Z)
+1Z = (1B, 1B)
+2A = (2Z, 2Z)
+2Z = (2B, 2B)
+2B = (2C, 2C)
+2C = (2Z, 2Z)",
+        )
+        .unwrap();
+        let cycles = network.ghost_cycles(&"*A".into(), &"*Z".into());
+
+        assert!(!lcm_shortcut_holds(&cycles));
+        assert_eq!(Some(4), first_meeting(&cycles));
+    }
+
+    #[test]
+    fn meets_before_settling() {
+        let cycles = [
+            Cycle {
+                prefix: vec![3],
+                start: 5,
+                length: 2,
+                hits: vec![],
+            },
+            Cycle {
+                prefix: vec![],
+                start: 0,
+                length: 3,
+                hits: vec![0],
+            },
+        ];
+        assert_eq!(Some(3), first_meeting(&cycles));
+    }
+
+    #[test]
+    fn never_meets() {
+        let cycle = |hit| Cycle {
+            prefix: vec![],
+            start: 0,
+            length: 2,
+            hits: vec![hit],
+        };
+        assert_eq!(None, first_meeting(&[cycle(0), cycle(1)]));
+        assert_eq!(None, first_meeting(&[]));
+    }
+}
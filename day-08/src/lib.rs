@@ -0,0 +1,299 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    convert::Infallible,
+    fmt,
+    str::FromStr,
+};
+
+use aoc_common::{Answer, ParseError, Solution};
+use itertools::Itertools;
+
+pub mod cycles;
+pub mod part1;
+pub mod part2;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed = Network;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::part2(parsed)
+    }
+}
+
+/// The directions and the node map they are followed through.
+#[derive(Debug)]
+pub struct Network {
+    pub directions: String,
+    nodes: BTreeMap<String, (String, String)>,
+}
+
+impl FromStr for Network {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut it = input.lines();
+
+        let directions = it.next().unwrap_or(input);
+        if directions.is_empty() {
+            return Err(ParseError::at(input, directions, "a line of directions"));
+        }
+        if let Some(idx) = directions.find(|ch| ch != 'L' && ch != 'R') {
+            let ch = directions[idx..].chars().next().unwrap_or_default();
+            return Err(ParseError::at(
+                input,
+                &directions[idx..idx + ch.len_utf8()],
+                "'L' or 'R'",
+            ));
+        }
+
+        let lines = it
+            .skip_while(|l| l.is_empty())
+            .map(|l| parse_map_line(input, l))
+            .collect::<Result<Vec<_>, _>>()?;
+        let defined: HashSet<&str> = lines.iter().map(|(key, _)| *key).collect();
+        if let Some(missing) = lines
+            .iter()
+            .flat_map(|(_, (left, right))| [*left, *right])
+            .find(|node| !defined.contains(node))
+        {
+            return Err(ParseError::at(input, missing, "a node defined in the map"));
+        }
+
+        let nodes = lines
+            .into_iter()
+            .map(|(key, (left, right))| (key.to_string(), (left.to_string(), right.to_string())))
+            .collect();
+        Ok(Network {
+            directions: directions.to_string(),
+            nodes,
+        })
+    }
+}
+
+impl Network {
+    /// The node reached from `node`, which must be in the network.
+    pub fn next(&self, node: &str, dir: char) -> &str {
+        let (left, right) = &self.nodes[node];
+        match dir {
+            'L' => left,
+            _ => right,
+        }
+    }
+
+    pub fn contains(&self, node: &str) -> bool {
+        self.nodes.contains_key(node)
+    }
+
+    /// Every node matching `is_start`, in name order.
+    pub fn starts<'a>(
+        &'a self,
+        is_start: impl Fn(&str) -> bool + 'a,
+    ) -> impl Iterator<Item = &'a str> {
+        self.nodes
+            .keys()
+            .map(String::as_str)
+            .filter(move |node| is_start(node))
+    }
+
+    /// Every node visited following the directions from `start` until one
+    /// matches `is_target`, both included.
+    pub fn path(
+        &self,
+        start: &str,
+        is_target: impl Fn(&str) -> bool,
+    ) -> Result<Vec<&str>, NetworkError> {
+        let mut node = self
+            .nodes
+            .get_key_value(start)
+            .map(|(key, _)| key.as_str())
+            .ok_or_else(|| NetworkError::UnknownNode(start.to_string()))?;
+        let mut path = vec![node];
+        let mut seen = HashSet::new();
+        for (idx, dir) in self.directions.chars().enumerate().cycle() {
+            if is_target(node) {
+                return Ok(path);
+            }
+            // Back in a state already left without reaching a target, so
+            // the walk would go round forever.
+            if !seen.insert((node, idx)) {
+                return Err(NetworkError::Unreachable(start.to_string()));
+            }
+            node = self.next(node, dir);
+            path.push(node);
+        }
+        unreachable!("directions are never empty")
+    }
+}
+
+/// A node name pattern, where `*` matches any run of characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern(String);
+
+impl Pattern {
+    pub fn matches(&self, node: &str) -> bool {
+        let mut parts = self.0.split('*');
+        let first = parts.next().unwrap_or_default();
+        let Some(mut rest) = node.strip_prefix(first) else {
+            return false;
+        };
+        let Some(last) = parts.next_back() else {
+            return rest.is_empty();
+        };
+        for part in parts {
+            match rest.find(part) {
+                Some(idx) => rest = &rest[idx + part.len()..],
+                None => return false,
+            }
+        }
+        rest.len() >= last.len() && rest.ends_with(last)
+    }
+}
+
+impl From<&str> for Pattern {
+    fn from(s: &str) -> Self {
+        Pattern(s.to_string())
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Pattern(s.to_string()))
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkError {
+    UnknownNode(String),
+    NoStart(Pattern),
+    /// Following the directions from this node never reaches a target.
+    Unreachable(String),
+    /// There is no step at which every start is on a target.
+    NeverMeet,
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetworkError::UnknownNode(node) => write!(f, "{node} is not in the network"),
+            NetworkError::NoStart(pattern) => write!(f, "no node matches {pattern}"),
+            NetworkError::Unreachable(node) => write!(f, "no target can be reached from {node}"),
+            NetworkError::NeverMeet => {
+                f.write_str("the walks are never on targets at the same step")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
+// really should have used nom on this one.
+fn parse_map_line<'a>(
+    input: &str,
+    map_line: &'a str,
+) -> Result<(&'a str, (&'a str, &'a str)), ParseError> {
+    let (key, rest) = map_line
+        .split_once('=')
+        .ok_or_else(|| ParseError::at(input, &map_line[map_line.len()..], "'='"))?;
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(ParseError::at(input, key, "a node name"));
+    }
+    let rest = rest.trim();
+    let (l, r) = rest
+        .split(", ")
+        .collect_tuple()
+        .ok_or_else(|| ParseError::at(input, rest, "'(LEFT, RIGHT)'"))?;
+    let left = l
+        .strip_prefix('(')
+        .ok_or_else(|| ParseError::at(input, l, "'('"))?;
+    let right = r
+        .strip_suffix(')')
+        .ok_or_else(|| ParseError::at(input, &r[r.len()..], "')'"))?;
+    Ok((key, (left, right)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reports_malformed_map_line() {
+        let input = "LR\n\nAAA = (BBB, CCC\nBBB = (AAA, AAA)";
+        let err = input.parse::<Network>().unwrap_err();
+        assert_eq!(
+            "line 3, column 16: expected ')', found end of line",
+            err.to_string()
+        );
+
+        let err = "LXR\n\nAAA = (AAA, AAA)".parse::<Network>().unwrap_err();
+        assert_eq!((1, 2, "X"), (err.line, err.column, err.snippet.as_str()));
+
+        let err = "LR\n\nAAA = (AAA, BBB)".parse::<Network>().unwrap_err();
+        assert_eq!(
+            "line 3, column 13: expected a node defined in the map, found \"BBB\"",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn returns_the_path() {
+        let network: Network = "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            Ok(vec!["AAA", "BBB", "AAA", "BBB", "AAA", "BBB", "ZZZ"]),
+            network.path("AAA", |n| n == "ZZZ")
+        );
+        assert_eq!(Ok(vec!["BBB"]), network.path("BBB", |n| n == "BBB"));
+        assert_eq!(
+            Err(NetworkError::UnknownNode("CCC".to_string())),
+            network.path("CCC", |n| n == "ZZZ")
+        );
+    }
+
+    #[test]
+    fn reports_unreachable_targets() {
+        let network: Network = "LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            Err(NetworkError::Unreachable("AAA".to_string())),
+            network.path("AAA", |n| n == "ZZZ")
+        );
+    }
+
+    #[test]
+    fn matches_patterns() {
+        let pattern = |p: &str| p.parse::<Pattern>().unwrap();
+
+        assert!(pattern("AAA").matches("AAA"));
+        assert!(!pattern("AAA").matches("AAAA"));
+        assert!(pattern("*A").matches("11A"));
+        assert!(!pattern("*A").matches("11B"));
+        assert!(pattern("1*").matches("1ZZ"));
+        assert!(pattern("*").matches(""));
+        assert!(pattern("A*B*C").matches("AxxBxC"));
+        assert!(!pattern("A*B*C").matches("AC"));
+        assert!(!pattern("AA*AA").matches("AAA"));
+    }
+}
@@ -0,0 +1,95 @@
+use aoc_common::Answer;
+
+use crate::{Network, NetworkError, Pattern};
+
+pub fn part1(network: &Network) -> Answer {
+    walk(network, &"AAA".into(), &"ZZZ".into()).into()
+}
+
+/// The fewest steps from a node matching `start` to one matching `end`.
+pub fn walk(network: &Network, start: &Pattern, end: &Pattern) -> Result<Answer, NetworkError> {
+    let mut result = Err(NetworkError::NoStart(start.clone()));
+    for node in network.starts(|node| start.matches(node)) {
+        match (network.path(node, |node| end.matches(node)), &result) {
+            (Ok(path), Ok(fewest)) if path.len() >= *fewest => {}
+            (Ok(path), _) => result = Ok(path.len()),
+            (Err(e), Err(_)) => result = Err(e),
+            (Err(_), Ok(_)) => {}
+        }
+    }
+    result.map(|len| (len - 1).into())
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use super::*;
+    use crate::Day08;
+
+    #[test]
+    fn cycles_counts_steps() {
+        let sample = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(Answer::Unsigned(6), Day08::solve_part1(sample).unwrap());
+    }
+
+    #[test]
+    fn example_part1() {
+        let sample = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+        assert_eq!(Answer::Unsigned(2), Day08::solve_part1(sample).unwrap());
+    }
+
+    #[test]
+    fn walks_between_patterns() {
+        let network = Day08::parse(
+            "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)",
+        )
+        .unwrap();
+
+        assert_eq!(
+            Ok(Answer::Unsigned(1)),
+            walk(&network, &"A*".into(), &"C*".into())
+        );
+        assert_eq!(
+            Err(NetworkError::Unreachable("BBB".to_string())),
+            walk(&network, &"BBB".into(), &"ZZZ".into())
+        );
+        assert_eq!(
+            Err(NetworkError::NoStart("QQQ".into())),
+            walk(&network, &"QQQ".into(), &"ZZZ".into())
+        );
+    }
+
+    #[test]
+    fn unreachable_end_is_unsolved() {
+        let sample = "L
+
+AAA = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(
+            Answer::Unsolved("no target can be reached from AAA".to_string()),
+            Day08::solve_part1(sample).unwrap()
+        );
+    }
+}
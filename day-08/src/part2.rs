@@ -0,0 +1,68 @@
+use aoc_common::Answer;
+
+use crate::{cycles::first_meeting, Network, NetworkError, Pattern};
+
+pub fn part2(network: &Network) -> Answer {
+    ghosts(network, &"*A".into(), &"*Z".into()).into()
+}
+
+/// The first step at which ghosts starting on every node matching `start`
+/// are all on nodes matching `end`.
+pub fn ghosts(network: &Network, start: &Pattern, end: &Pattern) -> Result<Answer, NetworkError> {
+    let cycles = network.ghost_cycles(start, end);
+    if cycles.is_empty() {
+        return Err(NetworkError::NoStart(start.clone()));
+    }
+    let stuck = network
+        .starts(|node| start.matches(node))
+        .zip(&cycles)
+        .find(|(_, cycle)| cycle.prefix.is_empty() && cycle.hits.is_empty());
+    if let Some((node, _)) = stuck {
+        return Err(NetworkError::Unreachable(node.to_string()));
+    }
+    first_meeting(&cycles)
+        .map(Answer::from)
+        .ok_or(NetworkError::NeverMeet)
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use super::*;
+    use crate::Day08;
+
+    const SAMPLE: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(Answer::Unsigned(6), Day08::solve_part2(SAMPLE).unwrap());
+    }
+
+    #[test]
+    fn reports_ghosts_that_cannot_finish() {
+        let network = Day08::parse(SAMPLE).unwrap();
+
+        assert_eq!(
+            Ok(Answer::Unsigned(2)),
+            ghosts(&network, &"11A".into(), &"*Z".into())
+        );
+        assert_eq!(
+            Err(NetworkError::Unreachable("11B".to_string())),
+            ghosts(&network, &"11*".into(), &"*Z".into())
+        );
+        assert_eq!(
+            Err(NetworkError::NoStart("*Q".into())),
+            ghosts(&network, &"*Q".into(), &"*Z".into())
+        );
+    }
+}
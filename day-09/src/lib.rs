@@ -0,0 +1,193 @@
+use std::fmt;
+
+use aoc_common::{
+    parse::{get_list_of_nums, parse_lines_with},
+    Answer, ParseError, Solution,
+};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed = Vec<History>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines_with(input, |line| {
+            let values = get_list_of_nums::<i64>(line)?;
+            History::new(&values).map_err(|e| {
+                let expected = match e {
+                    ExtrapolateError::NeverSettles => {
+                        "a history whose differences reach a row of zeros"
+                    }
+                    ExtrapolateError::Overflow => "values that fit in an i128",
+                };
+                ParseError::at(line, line, expected)
+            })
+        })
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::part2(parsed)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtrapolateError {
+    /// The differences ran out before a row of all zeros.
+    NeverSettles,
+    Overflow,
+}
+
+impl fmt::Display for ExtrapolateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtrapolateError::NeverSettles => {
+                f.write_str("the differences never reach a row of zeros")
+            }
+            ExtrapolateError::Overflow => f.write_str("a value does not fit in an i128"),
+        }
+    }
+}
+
+impl std::error::Error for ExtrapolateError {}
+
+/// A sensor history, kept as the first value of each row of its difference
+/// table. The value at any position `x` is then exactly
+/// `sum(C(x, k) * first[k])`, Newton's forward difference formula, which
+/// holds for positions before the history as well as after it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct History {
+    first: Vec<i128>,
+    len: usize,
+}
+
+impl History {
+    pub fn new(values: &[i64]) -> Result<Self, ExtrapolateError> {
+        let mut row: Vec<i128> = values.iter().map(|&v| v.into()).collect();
+        let mut first = vec![];
+        while row.iter().any(|&v| v != 0) {
+            first.push(row[0]);
+            row = differences(&row)?;
+        }
+        if row.is_empty() {
+            return Err(ExtrapolateError::NeverSettles);
+        }
+        Ok(Self {
+            first,
+            len: values.len(),
+        })
+    }
+
+    /// The value `steps` after the last reading.
+    pub fn ahead(&self, steps: u64) -> Result<i128, ExtrapolateError> {
+        let last = self.len as i128 - 1;
+        self.value_at(last + i128::from(steps))
+    }
+
+    /// The value `steps` before the first reading.
+    pub fn behind(&self, steps: u64) -> Result<i128, ExtrapolateError> {
+        self.value_at(-i128::from(steps))
+    }
+
+    /// The value at `x`, counting the first reading as 0.
+    pub fn value_at(&self, x: i128) -> Result<i128, ExtrapolateError> {
+        let mut value: i128 = 0;
+        // C(x, k), built up from C(x, k - 1) * (x - k + 1) / k, which always
+        // divides exactly.
+        let mut choose: i128 = 1;
+        for (k, &diff) in self.first.iter().enumerate() {
+            if k > 0 {
+                let k = k as i128;
+                choose = choose
+                    .checked_mul(x - k + 1)
+                    .ok_or(ExtrapolateError::Overflow)?
+                    / k;
+            }
+            value = choose
+                .checked_mul(diff)
+                .and_then(|term| value.checked_add(term))
+                .ok_or(ExtrapolateError::Overflow)?;
+        }
+        Ok(value)
+    }
+}
+
+fn differences(row: &[i128]) -> Result<Vec<i128>, ExtrapolateError> {
+    row.windows(2)
+        .map(|pair| {
+            pair[1]
+                .checked_sub(pair[0])
+                .ok_or(ExtrapolateError::Overflow)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn generates_next_based_on_diff() {
+        let sample = [0, 3, 6, 9, 12, 15];
+        assert_eq!(Ok(vec![3, 3, 3, 3, 3]), differences(&sample));
+    }
+
+    #[test]
+    fn extrapolates_both_ways() {
+        let history = History::new(&[1, 3, 6, 10, 15, 21]).unwrap();
+
+        assert_eq!(Ok(28), history.ahead(1));
+        assert_eq!(Ok(36), history.ahead(2));
+        assert_eq!(Ok(21), history.ahead(0));
+        assert_eq!(Ok(1), history.behind(0));
+        assert_eq!(Ok(0), history.behind(1));
+        // Triangular numbers continue as (x + 1)(x + 2) / 2 for negative x too.
+        assert_eq!(Ok(36), history.behind(10));
+    }
+
+    #[test]
+    fn matches_repeated_single_steps() {
+        let mut values: Vec<i64> = vec![10, 13, 16, 21, 30, 45];
+        let history = History::new(&values).unwrap();
+        for steps in 1..=20 {
+            let next = History::new(&values).unwrap().ahead(1).unwrap();
+            assert_eq!(Ok(next), history.ahead(steps));
+            values.push(next as i64);
+        }
+    }
+
+    #[test]
+    fn reports_unsettled_histories() {
+        assert_eq!(
+            Err(ExtrapolateError::NeverSettles),
+            History::new(&[1, 2, 4])
+        );
+        assert_eq!(Err(ExtrapolateError::NeverSettles), History::new(&[]));
+        assert!(History::new(&[0]).is_ok());
+        assert_eq!(
+            "the differences never reach a row of zeros",
+            ExtrapolateError::NeverSettles.to_string()
+        );
+
+        let err = Day09::parse("0 3 6\n1 2 4").unwrap_err();
+        assert_eq!(
+            "line 2, column 1: expected a history whose differences reach a row of zeros, \
+             found \"1 2 4\"",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn reports_overflow() {
+        let a = i64::MAX / 9;
+        let squares = History::new(&[0, a, 4 * a, 9 * a]).unwrap();
+        assert_eq!(Ok(i128::from(a) * 16), squares.ahead(1));
+        assert_eq!(Err(ExtrapolateError::Overflow), squares.ahead(u64::MAX));
+    }
+}
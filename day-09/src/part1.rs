@@ -0,0 +1,49 @@
+use aoc_common::Answer;
+
+use crate::{ExtrapolateError, History};
+
+pub fn part1(histories: &[History]) -> Answer {
+    histories
+        .iter()
+        .try_fold(0i128, |sum, h| {
+            sum.checked_add(h.ahead(1)?)
+                .ok_or(ExtrapolateError::Overflow)
+        })
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use super::*;
+    use crate::Day09;
+
+    #[test]
+    fn first_line_expansion() {
+        let sample = "0 3 6 9 12 15";
+
+        assert_eq!(Answer::Signed(18), Day09::solve_part1(sample).unwrap());
+    }
+
+    #[test]
+    fn example_part1() {
+        let sample = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+        assert_eq!(Answer::Signed(114), Day09::solve_part1(sample).unwrap());
+    }
+
+    #[test]
+    fn overflow_is_unsolved() {
+        let huge = || History {
+            first: vec![i128::MAX],
+            len: 1,
+        };
+        assert_eq!(
+            Answer::Unsolved("a value does not fit in an i128".to_string()),
+            part1(&[huge(), huge()])
+        );
+    }
+}
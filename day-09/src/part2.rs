@@ -0,0 +1,49 @@
+use aoc_common::Answer;
+
+use crate::{ExtrapolateError, History};
+
+pub fn part2(histories: &[History]) -> Answer {
+    histories
+        .iter()
+        .try_fold(0i128, |sum, h| {
+            sum.checked_add(h.behind(1)?)
+                .ok_or(ExtrapolateError::Overflow)
+        })
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use super::*;
+    use crate::Day09;
+
+    #[test]
+    fn subset_part2() {
+        let sample = "10 13 16 21 30 45";
+
+        assert_eq!(Answer::Signed(5), Day09::solve_part2(sample).unwrap());
+    }
+
+    #[test]
+    fn example_part2() {
+        let sample = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+        assert_eq!(Answer::Signed(2), Day09::solve_part2(sample).unwrap());
+    }
+
+    #[test]
+    fn overflow_is_unsolved() {
+        let huge = || History {
+            first: vec![i128::MAX],
+            len: 1,
+        };
+        assert_eq!(
+            Answer::Unsolved("a value does not fit in an i128".to_string()),
+            part2(&[huge(), huge()])
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use std::collections::VecDeque;
+
+use aoc_common::{polygon, Direction};
+use itertools::Itertools;
+
+use crate::connections;
+
+/// A tile's position, `(x, y)`.
+pub type Tile = (usize, usize);
+
+/// The pipes of a sketch, joined wherever two neighbouring pipes open onto
+/// each other. `S` is taken to open every way, so it joins whatever points
+/// back at it.
+#[derive(Debug)]
+pub struct PipeGraph<'a> {
+    grid: &'a [Vec<char>],
+    width: usize,
+    height: usize,
+}
+
+/// Pipes joined to each other, in row order.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Component {
+    pub tiles: Vec<Tile>,
+}
+
+/// A component in which every pipe joins exactly two others, in the order
+/// it is walked.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Loop {
+    pub tiles: Vec<Tile>,
+}
+
+/// A pipe opening onto the edge of the grid, the ground or a pipe that does
+/// not open back.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Dangling {
+    pub tile: Tile,
+    pub dir: Direction,
+}
+
+impl<'a> PipeGraph<'a> {
+    pub fn new(grid: &'a [Vec<char>]) -> Self {
+        Self {
+            grid,
+            width: grid.first().map_or(0, Vec::len),
+            height: grid.len(),
+        }
+    }
+
+    fn tile(&self, (x, y): Tile) -> char {
+        self.grid[y][x]
+    }
+
+    fn is_pipe(&self, tile: Tile) -> bool {
+        self.tile(tile) != '.'
+    }
+
+    fn opens(&self, tile: Tile, dir: Direction) -> bool {
+        let ch = self.tile(tile);
+        ch == 'S' || connections(ch).contains(&dir)
+    }
+
+    fn step(&self, (x, y): Tile, dir: Direction) -> Option<Tile> {
+        dir.step(x, y, self.width, self.height)
+    }
+
+    fn tiles(&self) -> impl Iterator<Item = Tile> + '_ {
+        (0..self.height)
+            .cartesian_product(0..self.width)
+            .map(|(y, x)| (x, y))
+            .filter(|&tile| self.is_pipe(tile))
+    }
+
+    /// The pipes `tile` is joined to.
+    pub fn neighbours(&self, tile: Tile) -> impl Iterator<Item = Tile> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter(move |&dir| self.opens(tile, dir))
+            .filter_map(move |dir| {
+                self.step(tile, dir)
+                    .filter(|&next| self.opens(next, dir.opposite()))
+            })
+    }
+
+    pub fn components(&self) -> Vec<Component> {
+        let mut seen = vec![vec![false; self.width]; self.height];
+        let mut components = vec![];
+        for start in self.tiles() {
+            if seen[start.1][start.0] {
+                continue;
+            }
+            seen[start.1][start.0] = true;
+            let mut tiles = vec![];
+            let mut queue = VecDeque::from([start]);
+            while let Some(tile) = queue.pop_front() {
+                tiles.push(tile);
+                for next in self.neighbours(tile) {
+                    if !seen[next.1][next.0] {
+                        seen[next.1][next.0] = true;
+                        queue.push_back(next);
+                    }
+                }
+            }
+            tiles.sort_by_key(|&(x, y)| (y, x));
+            components.push(Component { tiles });
+        }
+        components
+    }
+
+    /// Every closed loop, by the first tile of its component.
+    pub fn loops(&self) -> Vec<Loop> {
+        self.components()
+            .iter()
+            .filter_map(|component| self.as_loop(component))
+            .collect()
+    }
+
+    fn as_loop(&self, component: &Component) -> Option<Loop> {
+        let joined: Vec<Vec<Tile>> = component
+            .tiles
+            .iter()
+            .map(|&tile| self.neighbours(tile).collect())
+            .collect();
+        if joined.iter().any(|next| next.len() != 2) {
+            return None;
+        }
+
+        let start = component.tiles[0];
+        let mut tiles = vec![start];
+        let (mut prev, mut tile) = (start, joined[0][0]);
+        while tile != start {
+            tiles.push(tile);
+            let idx = component
+                .tiles
+                .binary_search_by_key(&(tile.1, tile.0), |&(x, y)| (y, x))
+                .expect("neighbours are in the same component");
+            let next = *joined[idx]
+                .iter()
+                .find(|&&next| next != prev)
+                .expect("every tile has two neighbours");
+            (prev, tile) = (tile, next);
+        }
+        Some(Loop { tiles })
+    }
+
+    pub fn dangling(&self) -> Vec<Dangling> {
+        self.tiles()
+            .filter(|&tile| self.tile(tile) != 'S')
+            .flat_map(|tile| {
+                connections(self.tile(tile))
+                    .iter()
+                    .filter(move |&&dir| {
+                        self.step(tile, dir)
+                            .is_none_or(|next| !self.opens(next, dir.opposite()))
+                    })
+                    .map(move |&dir| Dangling { tile, dir })
+            })
+            .collect()
+    }
+}
+
+impl Loop {
+    pub fn length(&self) -> usize {
+        self.tiles.len()
+    }
+
+    /// Tiles strictly inside the loop.
+    pub fn enclosed(&self) -> usize {
+        let corners = self
+            .tiles
+            .iter()
+            .map(|&(x, y)| (x as i64, y as i64))
+            .collect_vec();
+        polygon::interior_points(&corners) as usize
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::grid::parse_grid;
+
+    use super::*;
+
+    #[test]
+    fn finds_loops_and_dangling_pipes() {
+        let grid = parse_grid(
+            "F7.F-7
+LJ.|.|
+-..L-J",
+        );
+        let graph = PipeGraph::new(&grid);
+
+        assert_eq!(3, graph.components().len());
+        let loops = graph.loops();
+        assert_eq!(
+            vec![(4, 0, 0), (8, 1, 3)],
+            loops
+                .iter()
+                .map(|l| (l.length(), l.enclosed(), l.tiles[0].0))
+                .collect_vec()
+        );
+        assert_eq!(
+            vec![
+                Dangling {
+                    tile: (0, 2),
+                    dir: Direction::East
+                },
+                Dangling {
+                    tile: (0, 2),
+                    dir: Direction::West
+                }
+            ],
+            graph.dangling()
+        );
+    }
+
+    #[test]
+    fn joins_start_to_the_pipes_facing_it() {
+        let grid = parse_grid(
+            "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF",
+        );
+        let graph = PipeGraph::new(&grid);
+
+        assert_eq!(
+            vec![(1, 2), (2, 1)],
+            graph.neighbours((1, 1)).sorted().collect_vec()
+        );
+        let main = graph
+            .loops()
+            .into_iter()
+            .find(|l| l.tiles.contains(&(1, 1)))
+            .unwrap();
+        assert_eq!((8, 1), (main.length(), main.enclosed()));
+        assert!(graph
+            .dangling()
+            .iter()
+            .all(|d| !main.tiles.contains(&d.tile)));
+    }
+
+    #[test]
+    fn branches_are_not_loops() {
+        let grid = parse_grid(
+            "F7.
+SJ.
+L-7",
+        );
+        let graph = PipeGraph::new(&grid);
+
+        assert_eq!(1, graph.components().len());
+        assert!(graph.loops().is_empty());
+        assert_eq!(
+            vec![Dangling {
+                tile: (2, 2),
+                dir: Direction::South
+            }],
+            graph.dangling()
+        );
+    }
+}
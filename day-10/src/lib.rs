@@ -0,0 +1,393 @@
+use std::{fmt, hash::Hash, str::FromStr};
+
+use aoc_common::grid::parse_tiles;
+use aoc_common::{Answer, Direction, ParseError, Solution};
+use graph::PipeGraph;
+use itertools::Itertools;
+
+pub mod graph;
+pub mod part1;
+pub mod part2;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Sketch;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::part2(parsed)
+    }
+}
+
+#[derive(Debug)]
+pub struct Sketch {
+    pub grid: Vec<Vec<char>>,
+    pub start: Loc,
+}
+
+impl FromStr for Sketch {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let grid = parse_tiles(input, "|-LJ7F.S")?;
+        let max_y = grid.len().saturating_sub(1);
+        let max_x = grid
+            .first()
+            .and_then(|row| row.len().checked_sub(1))
+            .ok_or_else(|| ParseError::at_end(input, "a row of pipes"))?;
+
+        let start = input
+            .lines()
+            .enumerate()
+            .find_map(|(y, line)| {
+                line.chars()
+                    .find_position(|ch| ch == &'S')
+                    .map(|(x, _)| Loc {
+                        x,
+                        y,
+                        max_y,
+                        max_x,
+                        steps: 0,
+                    })
+            })
+            .ok_or_else(|| ParseError::at_end(input, "a start tile 'S'"))?;
+
+        Ok(Sketch { grid, start })
+    }
+}
+
+impl Sketch {
+    pub fn graph(&self) -> PipeGraph<'_> {
+        PipeGraph::new(&self.grid)
+    }
+
+    /// The grid with `S` replaced by the pipe that connects its neighbours.
+    pub fn with_start_replaced(&self) -> Vec<Vec<char>> {
+        let mut map = self.grid.clone();
+        let replacement = swap_start(&self.start, map.as_slice());
+        map[self.start.y][self.start.x] = replacement;
+        map
+    }
+
+    /// The tiles of the loop through `S`, in the order they are walked
+    /// starting from `S`.
+    pub fn main_loop(&self) -> Result<Vec<(usize, usize)>, BrokenLoop> {
+        let map = self.with_start_replaced();
+        let (width, height) = (map[0].len(), map.len());
+        let start = (self.start.x, self.start.y);
+
+        let mut tiles = vec![];
+        let mut tile = start;
+        let mut came_from = None;
+        loop {
+            tiles.push(tile);
+            // Only step onto a pipe that opens back this way, or the walk
+            // can wander onto another loop and never return to `S`.
+            let (dir, next) = connections(map[tile.1][tile.0])
+                .iter()
+                .find(|&&d| Some(d) != came_from)
+                .and_then(|&dir| {
+                    let next = dir.step(tile.0, tile.1, width, height)?;
+                    connections(map[next.1][next.0])
+                        .contains(&dir.opposite())
+                        .then_some((dir, next))
+                })
+                .ok_or(BrokenLoop { tile })?;
+            if next == start {
+                return Ok(tiles);
+            }
+            tile = next;
+            came_from = Some(dir.opposite());
+        }
+    }
+
+    /// Walks the loop out from `S` in both directions at once, marking the
+    /// tiles on it with `*`. Returns the marked map and the last tiles
+    /// reached, where the two walks meet.
+    pub fn walk_loop(&self) -> (Vec<Vec<char>>, Vec<Loc>) {
+        let mut map = self.with_start_replaced();
+
+        let mut stack: Vec<Vec<Loc>> = vec![vec![self.start]];
+        let mut farthest: Vec<Loc> = vec![];
+        while let Some(curr_locs) = stack.pop() {
+            let next_locs = curr_locs
+                .iter()
+                .flat_map(|loc| {
+                    let ch = loc.char_at(&map);
+                    map[loc.y][loc.x] = '*'; // mark seen
+                    loc.next_for(&ch)
+                })
+                .collect_vec()
+                .into_iter()
+                .dedup()
+                .filter(|loc| loc.is_ch_valid(&map))
+                .collect_vec();
+
+            if next_locs.is_empty() && stack.is_empty() {
+                farthest = curr_locs;
+                break;
+            } else {
+                stack.push(next_locs);
+            }
+        }
+        (map, farthest)
+    }
+}
+
+/// The walk round the loop through `S` cannot go on from `tile`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrokenLoop {
+    pub tile: (usize, usize),
+}
+
+impl fmt::Display for BrokenLoop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (x, y) = self.tile;
+        write!(f, "the loop through S is broken after ({x}, {y})")
+    }
+}
+
+impl std::error::Error for BrokenLoop {}
+
+#[derive(Debug, Eq, Clone, Copy)]
+pub struct Loc {
+    pub x: usize,
+    pub y: usize,
+    pub max_x: usize,
+    pub max_y: usize,
+    pub steps: usize,
+}
+
+impl Hash for Loc {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+        self.max_x.hash(state);
+        self.max_y.hash(state);
+    }
+}
+
+impl PartialEq for Loc {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x
+            && self.y == other.y
+            && self.max_x == other.max_x
+            && self.max_y == other.max_y
+    }
+}
+
+impl Loc {
+    fn is_ymax(&self) -> bool {
+        self.y >= self.max_y
+    }
+
+    fn is_ymin(&self) -> bool {
+        self.y == 0
+    }
+
+    fn is_xmax(&self) -> bool {
+        self.x >= self.max_x
+    }
+
+    fn is_xmin(&self) -> bool {
+        self.x == 0
+    }
+
+    pub fn is_ch_valid(&self, map: &[Vec<char>]) -> bool {
+        let ch = self.char_at(map);
+        !['.', '*'].contains(&ch)
+    }
+
+    fn move_dir(&self, dir: &Direction) -> Option<Self> {
+        use Direction::*;
+        match dir {
+            North if !self.is_ymin() => Some(Self {
+                y: self.y - 1,
+                steps: self.steps + 1,
+                ..*self
+            }),
+            South if !self.is_ymax() => Some(Self {
+                y: self.y + 1,
+                steps: self.steps + 1,
+                ..*self
+            }),
+            East if !self.is_xmax() => Some(Self {
+                x: self.x + 1,
+                steps: self.steps + 1,
+                ..*self
+            }),
+            West if !self.is_xmin() => Some(Self {
+                x: self.x - 1,
+                steps: self.steps + 1,
+                ..*self
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn next_for(&self, ch: &char) -> Vec<Self> {
+        use Direction::*;
+        match ch {
+            'S' => Direction::ALL
+                .iter()
+                .flat_map(|d| self.move_dir(d))
+                .collect(),
+            '|' => [North, South]
+                .iter()
+                .flat_map(|d| self.move_dir(d))
+                .collect(),
+            '-' => [East, West].iter().flat_map(|d| self.move_dir(d)).collect(),
+            'L' => [North, East]
+                .iter()
+                .flat_map(|d| self.move_dir(d))
+                .collect(),
+            'J' => [North, West]
+                .iter()
+                .flat_map(|d| self.move_dir(d))
+                .collect(),
+            '7' => [South, West]
+                .iter()
+                .flat_map(|d| self.move_dir(d))
+                .collect(),
+            'F' => [South, East]
+                .iter()
+                .flat_map(|d| self.move_dir(d))
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    pub fn char_at(&self, map: &[Vec<char>]) -> char {
+        map[self.y][self.x]
+    }
+}
+/// The sides a tile's pipe opens onto.
+pub fn connections(tile: char) -> &'static [Direction] {
+    use Direction::*;
+    match tile {
+        '|' => &[North, South],
+        '-' => &[East, West],
+        'L' => &[North, East],
+        'J' => &[North, West],
+        '7' => &[South, West],
+        'F' => &[South, East],
+        _ => &[],
+    }
+}
+
+fn swap_start(start: &Loc, map: &[Vec<char>]) -> char {
+    use Direction::*;
+    let connected_to: Vec<Direction> = Direction::ALL
+        .into_iter()
+        .filter(|d| {
+            start
+                .move_dir(d)
+                .and_then(|loc| match (d, loc.char_at(map)) {
+                    (North, '|') => Some(loc),
+                    (North, '7') => Some(loc),
+                    (North, 'F') => Some(loc),
+                    (South, '|') => Some(loc),
+                    (South, 'L') => Some(loc),
+                    (South, 'J') => Some(loc),
+                    (East, '-') => Some(loc),
+                    (East, 'J') => Some(loc),
+                    (East, '7') => Some(loc),
+                    (West, '-') => Some(loc),
+                    (West, 'L') => Some(loc),
+                    (West, 'F') => Some(loc),
+                    _ => None,
+                })
+                .is_some()
+        })
+        .collect();
+    // dbg!(&connected_to);
+
+    match connected_to.as_slice() {
+        [North, South] => '|',
+        [East, West] => '-',
+        [North, East] => 'L',
+        [North, West] => 'J',
+        [South, West] => '7',
+        [South, East] => 'F',
+        n => panic!("Unable to replace S. {:?}", n),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn char_at() {
+        let mut sample = ".....
+.S-7.
+.|.|.
+.L-J.
+....."
+            .lines()
+            .map(|l| l.chars().collect_vec())
+            .collect_vec();
+        let j = Loc {
+            x: 3,
+            y: 3,
+            max_y: 4,
+            max_x: 4,
+            steps: 0,
+        };
+        let s = Loc {
+            x: 1,
+            y: 1,
+            max_y: 4,
+            max_x: 4,
+            steps: 0,
+        };
+
+        let r = swap_start(&s, sample.as_slice());
+        sample[s.y][s.x] = r;
+        assert_eq!('J', j.char_at(&sample));
+    }
+
+    #[test]
+    fn walks_the_main_loop() {
+        let sketch: Sketch = "-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF".parse().unwrap();
+        assert_eq!(
+            vec![
+                (1, 1),
+                (1, 2),
+                (1, 3),
+                (2, 3),
+                (3, 3),
+                (3, 2),
+                (3, 1),
+                (2, 1)
+            ],
+            sketch.main_loop().unwrap()
+        );
+    }
+
+    #[test]
+    fn stops_where_the_loop_breaks() {
+        // `F` at (1, 2) does not open north, so the walk must not enter it.
+        let sketch: Sketch = ".S-7.\n.|.|.\n.F-7.\n.L-J.".parse().unwrap();
+        assert_eq!(Err(BrokenLoop { tile: (1, 1) }), sketch.main_loop());
+    }
+
+    #[test]
+    fn rejects_sketch_without_start() {
+        let err = ".F7\n.LJ".parse::<Sketch>().unwrap_err();
+        assert_eq!(
+            "line 2, column 4: expected a start tile 'S', found end of line",
+            err.to_string()
+        );
+
+        let err = "S-7\n|x|".parse::<Sketch>().unwrap_err();
+        assert_eq!((2, 2, "x"), (err.line, err.column, err.snippet.as_str()));
+    }
+}
@@ -0,0 +1,40 @@
+use aoc_common::Answer;
+
+use crate::Sketch;
+
+pub fn part1(sketch: &Sketch) -> Answer {
+    let (_, farthest) = sketch.walk_loop();
+    farthest
+        .iter()
+        .map(|l| l.steps)
+        .max()
+        .expect("highest step.")
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day10;
+    #[test]
+    fn base_case() {
+        let sample = ".....
+.S-7.
+.|.|.
+.L-J.
+.....";
+        assert_eq!(Answer::Unsigned(4), Day10::solve_part1(sample).unwrap());
+    }
+
+    #[test]
+    fn example_part1() {
+        let sample = "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...";
+
+        assert_eq!(Answer::Unsigned(8), Day10::solve_part1(sample).unwrap());
+    }
+}
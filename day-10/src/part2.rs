@@ -0,0 +1,333 @@
+use std::{cmp::min, collections::HashSet};
+
+use aoc_common::{polygon, Answer, Direction};
+use itertools::Itertools;
+
+use crate::{connections, BrokenLoop, Sketch};
+
+/// The loop's tiles are lattice points on its boundary, so Pick's theorem
+/// counts the tiles inside from the shoelace area.
+pub fn part2(sketch: &Sketch) -> Answer {
+    sketch
+        .main_loop()
+        .map(|tiles| {
+            let corners = tiles
+                .into_iter()
+                .map(|(x, y)| (x as i64, y as i64))
+                .collect_vec();
+            polygon::interior_points(&corners) as usize
+        })
+        .into()
+}
+
+/// Counts the enclosed tiles by casting a ray right from each tile off the
+/// loop and counting the loop's south-facing pipes it crosses.
+pub fn ray_cast(sketch: &Sketch) -> usize {
+    let (map, _) = sketch.walk_loop();
+
+    // `S` only crosses the ray if the pipe under it faces south.
+    let mut masked_original = sketch.with_start_replaced();
+    map.iter().enumerate().for_each(|(y, l)| {
+        l.iter().enumerate().for_each(|(x, ch)| match ch {
+            '*' => (),
+            _ => masked_original[y][x] = '.',
+        })
+    });
+
+    let mut inside = 0;
+    for line in masked_original.iter() {
+        for (x, ch) in line.iter().enumerate() {
+            if *ch == '.' {
+                let after = min(x + 1, sketch.start.max_x);
+                let count = line[after..]
+                    .iter()
+                    .filter(|&ch| ['F', '7', '|'].contains(ch))
+                    .count();
+                if count % 2 == 1 {
+                    inside += 1;
+                }
+            }
+        }
+    }
+    inside
+}
+
+/// Counts the enclosed tiles by blowing each tile up to 3×3, so the flood
+/// can squeeze between pipes that run side by side, and flooding in from
+/// outside the loop.
+pub fn flood_fill(sketch: &Sketch) -> Result<usize, BrokenLoop> {
+    let map = sketch.with_start_replaced();
+    let (width, height) = (map[0].len(), map.len());
+    let on_loop: HashSet<_> = sketch.main_loop()?.into_iter().collect();
+
+    // A blank border lets the flood get round pipes on the edge of the grid.
+    let (big_width, big_height) = (3 * width + 2, 3 * height + 2);
+    let centre = |x: usize, y: usize| (3 * x + 2, 3 * y + 2);
+    let mut blocked = vec![vec![false; big_width]; big_height];
+    for &(x, y) in &on_loop {
+        let (cx, cy) = centre(x, y);
+        blocked[cy][cx] = true;
+        for dir in connections(map[y][x]) {
+            let (px, py) = dir
+                .step(cx, cy, big_width, big_height)
+                .expect("the border keeps pipes inside");
+            blocked[py][px] = true;
+        }
+    }
+
+    let mut outside = vec![vec![false; big_width]; big_height];
+    outside[0][0] = true;
+    let mut stack = vec![(0, 0)];
+    while let Some((x, y)) = stack.pop() {
+        for dir in Direction::ALL {
+            if let Some((nx, ny)) = dir.step(x, y, big_width, big_height) {
+                if !blocked[ny][nx] && !outside[ny][nx] {
+                    outside[ny][nx] = true;
+                    stack.push((nx, ny));
+                }
+            }
+        }
+    }
+
+    Ok((0..height)
+        .cartesian_product(0..width)
+        .filter(|&(y, x)| {
+            let (cx, cy) = centre(x, y);
+            !on_loop.contains(&(x, y)) && !outside[cy][cx]
+        })
+        .count())
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use super::*;
+    use crate::Day10;
+
+    const BASE_CASE: &str = ".....
+.S-7.
+.|.|.
+.L-J.
+.....";
+
+    const EXAMPLE: &str = "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+
+    const RANDOM_BITS: &str = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+    const NON_MAIN_LOOP: &str = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+    #[test]
+    fn base_case() {
+        assert_eq!(Answer::Unsigned(1), Day10::solve_part2(BASE_CASE).unwrap());
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(Answer::Unsigned(4), Day10::solve_part2(EXAMPLE).unwrap());
+    }
+
+    #[test]
+    fn random_bits() {
+        assert_eq!(
+            Answer::Unsigned(8),
+            Day10::solve_part2(RANDOM_BITS).unwrap()
+        );
+    }
+
+    #[test]
+    fn non_main_loop() {
+        assert_eq!(
+            Answer::Unsigned(10),
+            Day10::solve_part2(NON_MAIN_LOOP).unwrap()
+        );
+    }
+
+    #[test]
+    fn broken_loop_is_unsolved() {
+        let sketch = Day10::parse(".S-7.\n.|.|.\n.F-7.\n.L-J.").unwrap();
+        assert_eq!(
+            Answer::Unsolved("the loop through S is broken after (1, 1)".to_string()),
+            part2(&sketch)
+        );
+        assert_eq!(Err(BrokenLoop { tile: (1, 1) }), flood_fill(&sketch));
+    }
+
+    fn assert_methods_agree(sample: &str) {
+        let sketch = Day10::parse(sample).unwrap();
+        let enclosed = ray_cast(&sketch);
+        assert_eq!(Ok(enclosed), flood_fill(&sketch), "{sample}");
+        assert_eq!(Answer::from(enclosed), part2(&sketch), "{sample}");
+    }
+
+    #[test]
+    fn agrees_with_ray_cast() {
+        for sample in [BASE_CASE, EXAMPLE, RANDOM_BITS, NON_MAIN_LOOP] {
+            assert_methods_agree(sample);
+        }
+    }
+
+    #[test]
+    fn agrees_with_ray_cast_on_random_loops() {
+        let mut rng = XorShift(0x2023_1210);
+        for _ in 0..500 {
+            assert_methods_agree(&random_sketch(&mut rng, 6));
+        }
+    }
+
+    struct XorShift(u64);
+
+    impl XorShift {
+        fn below(&mut self, n: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % n as u64) as usize
+        }
+    }
+
+    /// A loop round a random blob of cells on an `n`×`n` grid, drawn with a
+    /// tile for each cell corner and edge and junk pipes everywhere else.
+    fn random_sketch(rng: &mut XorShift, n: usize) -> String {
+        loop {
+            let mut cells = HashSet::from([(rng.below(n), rng.below(n))]);
+            let target = 1 + rng.below(n * n / 2);
+            while cells.len() < target {
+                let &(x, y) = cells.iter().sorted().nth(rng.below(cells.len())).unwrap();
+                let dir = Direction::ALL[rng.below(4)];
+                if let Some(cell) = dir.step(x, y, n, n) {
+                    cells.insert(cell);
+                }
+            }
+            if let Some(sketch) = draw_boundary(rng, &cells, n) {
+                return sketch;
+            }
+        }
+    }
+
+    /// `None` if the blob has holes or cells touching only at a corner, as
+    /// then its boundary is not a single loop.
+    fn draw_boundary(
+        rng: &mut XorShift,
+        cells: &HashSet<(usize, usize)>,
+        n: usize,
+    ) -> Option<String> {
+        use Direction::*;
+
+        let size = 2 * n + 1;
+        let mut grid: Vec<Vec<char>> = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| b"|-LJ7F."[rng.below(7)] as char)
+                    .collect()
+            })
+            .collect();
+
+        // Cell corners are the even tiles, and the tile between two corners
+        // is the pipe along that side.
+        let mut exits = vec![vec![vec![]; size]; size];
+        let mut on_loop = HashSet::new();
+        for &(x, y) in cells {
+            let (x, y) = (2 * x, 2 * y);
+            for side in Direction::ALL {
+                if side
+                    .step(x / 2, y / 2, n, n)
+                    .is_some_and(|c| cells.contains(&c))
+                {
+                    continue;
+                }
+                let (a, b, pipe, (a_exit, b_exit)) = match side {
+                    North => ((x, y), (x + 2, y), '-', (East, West)),
+                    South => ((x, y + 2), (x + 2, y + 2), '-', (East, West)),
+                    West => ((x, y), (x, y + 2), '|', (South, North)),
+                    East => ((x + 2, y), (x + 2, y + 2), '|', (South, North)),
+                };
+                exits[a.1][a.0].push(a_exit);
+                exits[b.1][b.0].push(b_exit);
+                let middle = ((a.0 + b.0) / 2, (a.1 + b.1) / 2);
+                grid[middle.1][middle.0] = pipe;
+                on_loop.insert(middle);
+            }
+        }
+
+        let mut corners = vec![];
+        for (y, row) in exits.iter_mut().enumerate() {
+            for (x, dirs) in row.iter_mut().enumerate() {
+                dirs.sort();
+                grid[y][x] = match dirs.as_slice() {
+                    [] => continue,
+                    [North, South] => '|',
+                    [East, West] => '-',
+                    [North, East] => 'L',
+                    [North, West] => 'J',
+                    [South, West] => '7',
+                    [South, East] => 'F',
+                    _ => return None,
+                };
+                corners.push((x, y));
+                on_loop.insert((x, y));
+            }
+        }
+
+        // With a hole there is more than one loop, so the first is too short.
+        let start = corners[0];
+        let (mut tile, mut came_from, mut length) = (start, None, 0);
+        loop {
+            let dir = *exits[tile.1][tile.0]
+                .iter()
+                .find(|&&d| Some(d) != came_from)
+                .unwrap();
+            let (dx, dy) = dir.delta();
+            tile = (
+                tile.0.wrapping_add_signed(2 * dx as isize),
+                tile.1.wrapping_add_signed(2 * dy as isize),
+            );
+            came_from = Some(dir.opposite());
+            length += 1;
+            if tile == start {
+                break;
+            }
+        }
+        if length != corners.len() {
+            return None;
+        }
+
+        // Junk next to `S` could look like a third way out of it.
+        let (sx, sy) = corners[rng.below(corners.len())];
+        grid[sy][sx] = 'S';
+        for dir in Direction::ALL {
+            if let Some(next) = dir.step(sx, sy, size, size) {
+                if !on_loop.contains(&next) {
+                    grid[next.1][next.0] = '.';
+                }
+            }
+        }
+        Some(grid.iter().map(|row| row.iter().join("")).join("\n"))
+    }
+}
@@ -1,120 +1,73 @@
-use std::{iter, cmp::{max, min}};
-
-use itertools::Itertools;
-
-fn main() {
-    let input = include_str!("./input1.txt");
-    let output = part2(input, 1_000_000);
-    dbg!(output);
+use std::{fs::File, io::BufWriter, path::PathBuf};
+
+use aoc_common::{
+    input::{exit_with_error, InputArgs},
+    Solution,
+};
+use clap::Parser;
+use day_11::{part2::part2, Day11, INFLATION_FACTOR};
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+/// Sums the distances between galaxies, and answers questions about them.
+#[derive(Parser, Debug)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+    /// How many times wider each empty row and column becomes.
+    #[arg(long, default_value_t = INFLATION_FACTOR)]
+    inflation: u64,
+    /// Also list the galaxies nearest this one, by index in reading order.
+    #[arg(long)]
+    nearest: Option<usize>,
+    /// How many galaxies `--nearest` lists.
+    #[arg(short, default_value_t = 5)]
+    k: usize,
+    /// Also print the two galaxies farthest apart.
+    #[arg(long)]
+    farthest: bool,
+    /// Also write the distance between every pair of galaxies to this CSV file.
+    #[arg(long)]
+    csv: Option<PathBuf>,
 }
 
-fn rows_to_expand(spacemap: &str) -> impl Iterator<Item = usize> + '_ {
-    spacemap
-        .lines()
-        .enumerate()
-        .filter(|(_idx, l)| l.chars().all(|ch| ch == '.'))
-        .map(|(idx, _l)| idx)
-}
-
-fn cols_to_expand(spacemap: &str) -> impl Iterator<Item = usize> + '_ {
-    let max_x = spacemap
-        .lines()
-        .next()
-        .and_then(|l| Some(l.chars().count() - 1))
-        .expect("size of first line has a max");
-
-    (0..=max_x).filter(|x| {
-        spacemap.lines().all(|l| match l.chars().nth(*x) {
-            Some('.') => true,
-            _ => false,
-        })
-    })
-}
-
-#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
-struct Loc {
-    x: usize,
-    y: usize,
-}
-
-impl Loc {
-    fn steps(&self, other: &Self, rows_to_expand: &[usize], cols_to_expand: &[usize], inflation_factor: usize) -> usize {
-        let x_range = min(self.x, other.x)..=max(self.x, other.x);
-        let y_range = min(self.y, other.y)..=max(self.y, other.y);
-        // dbg!(rows_to_expand);
-
-        let cross_y = rows_to_expand.iter().filter(|r| y_range.contains(r)).count();
-        let cross_x = cols_to_expand.iter().filter(|r| x_range.contains(r)).count();
-        // dbg!(x_range, y_range, cross_x, cross_y);
-        let dx = self.x.abs_diff(other.x);
-        let dy = self.y.abs_diff(other.y);
-        dx + (inflation_factor * cross_x) + dy + (inflation_factor * cross_y) - (1 * cross_x) - (1 * cross_y)
+fn main() {
+    #[cfg(feature = "dhat-heap")]
+    let _profiler = dhat::Profiler::builder()
+        .file_name(aoc_common::profile::report_path(11, "part2-heap.json"))
+        .build();
+    #[cfg(all(feature = "dhat-ad-hoc", not(feature = "dhat-heap")))]
+    let _profiler = dhat::Profiler::builder()
+        .ad_hoc()
+        .file_name(aoc_common::profile::report_path(11, "part2-ad-hoc.json"))
+        .build();
+
+    let args = Args::parse();
+    let input = args.input.load(11).unwrap_or_else(|e| exit_with_error(e));
+    let image = Day11::parse(&input).unwrap_or_else(|e| exit_with_error(e));
+    let universe = image.expand(args.inflation);
+
+    if let Some(galaxy) = args.nearest {
+        let nearest = universe
+            .nearest(galaxy, args.k)
+            .unwrap_or_else(|| exit_with_error(format!("there is no galaxy {galaxy}")));
+        for (other, distance) in nearest {
+            println!("galaxy {other}: {distance}");
+        }
     }
-}
-
-fn part2(input: &str, inflation_factor: usize) -> String {
-    let rows_to_expand = rows_to_expand(input).collect_vec();
-    let cols_to_expand = cols_to_expand(input).collect_vec();
-
-    let galaxy_loc = input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, l)| iter::repeat(y).zip(l.chars().enumerate()))
-        .flat_map(|(y, (x, ch))| match ch {
-            '#' => Some(Loc { x, y }),
-            _ => None,
-        }).collect_vec();
-
-    galaxy_loc.iter().enumerate().flat_map(|(idx, loc)| {
-        galaxy_loc[idx+1..].iter().zip(iter::repeat(loc))
-    })
-        .map(|(a,b)| {
-            let distance = a.steps(b, &rows_to_expand, &cols_to_expand, inflation_factor);
-            // let msg = format!("{:?}-{:?} steps: {}", a, b, distance);
-            // dbg!(msg);
-            distance
-        })
-        // .inspect(|v| {dbg!(v);})
-        .sum::<usize>()
-        .to_string()
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    // #[ignore]
-    #[test]
-    fn simplified_example() {
-        let sample = "...#......
-.......*..
-*.........
-..........
-......*...
-.*........
-.........*
-..........
-.......#..
-*...*.....";
-
-        assert_eq!("15", part2(sample, 2));
-
+    if args.farthest {
+        if let Some((a, b, distance)) = universe.farthest_pair() {
+            println!("farthest: galaxies {a} and {b}, {distance} apart");
+        }
     }
-
-    #[test]
-    // #[ignore]
-    fn example_part2() {
-        let sample = "...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....";
-
-        assert_eq!("1030", part2(sample, 10));
-        assert_eq!("8410", part2(sample, 100));
+    if let Some(path) = &args.csv {
+        File::create(path)
+            .and_then(|file| universe.write_csv(BufWriter::new(file)))
+            .unwrap_or_else(|e| exit_with_error(format!("{}: {e}", path.display())));
     }
+
+    let output = part2(&image, args.inflation);
+    dbg!(output);
 }
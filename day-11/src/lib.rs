@@ -0,0 +1,157 @@
+use std::{iter, str::FromStr};
+
+use aoc_common::{Answer, ParseError, Solution};
+use itertools::Itertools;
+
+pub mod part1;
+pub mod part2;
+pub mod universe;
+
+use universe::Universe;
+
+/// How many times wider each empty row and column is in part 2.
+pub const INFLATION_FACTOR: u64 = 1_000_000;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Image;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::part2(parsed, INFLATION_FACTOR)
+    }
+}
+
+#[derive(Debug)]
+pub struct Image {
+    pub galaxies: Vec<Loc>,
+    pub width: usize,
+    pub height: usize,
+    pub rows_to_expand: Vec<usize>,
+    pub cols_to_expand: Vec<usize>,
+}
+
+impl FromStr for Image {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let Some(first) = input.lines().next() else {
+            return Err(ParseError::at_end(input, "a row of the image"));
+        };
+        // Expanding looks galaxies up by column, so every row must be as
+        // wide as the first.
+        let width = first.chars().count();
+        for line in input.lines() {
+            if line.chars().count() != width {
+                let end = line
+                    .char_indices()
+                    .nth(width)
+                    .map_or(line.len(), |(idx, _)| idx);
+                let expected = format!("a row of {width} tiles");
+                return Err(ParseError::at(input, &line[end..], expected));
+            }
+        }
+
+        let galaxies = input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, l)| iter::repeat(y).zip(l.chars().enumerate()))
+            .flat_map(|(y, (x, ch))| match ch {
+                '#' => Some(Loc { x, y }),
+                _ => None,
+            })
+            .collect_vec();
+
+        Ok(Image {
+            galaxies,
+            width,
+            height: input.lines().count(),
+            rows_to_expand: rows_to_expand(input).collect_vec(),
+            cols_to_expand: cols_to_expand(input).collect_vec(),
+        })
+    }
+}
+
+impl Image {
+    /// The galaxies once every empty row and column has grown to
+    /// `inflation_factor` rows or columns.
+    pub fn expand(&self, inflation_factor: u64) -> Universe {
+        let xs = expanded_starts(self.width, &self.cols_to_expand, inflation_factor);
+        let ys = expanded_starts(self.height, &self.rows_to_expand, inflation_factor);
+        Universe {
+            galaxies: self.galaxies.iter().map(|g| (xs[g.x], ys[g.y])).collect(),
+        }
+    }
+}
+
+/// Where each of `len` rows or columns starts after expanding, as a running
+/// sum of their widths.
+fn expanded_starts(len: usize, empty: &[usize], inflation_factor: u64) -> Vec<u128> {
+    let mut widths = vec![1; len];
+    for &idx in empty {
+        widths[idx] = inflation_factor as u128;
+    }
+    widths
+        .iter()
+        .scan(0, |start, width| {
+            let here = *start;
+            *start += width;
+            Some(here)
+        })
+        .collect()
+}
+
+fn rows_to_expand(spacemap: &str) -> impl Iterator<Item = usize> + '_ {
+    spacemap
+        .lines()
+        .enumerate()
+        .filter(|(_idx, l)| l.chars().all(|ch| ch == '.'))
+        .map(|(idx, _l)| idx)
+}
+
+fn cols_to_expand(spacemap: &str) -> impl Iterator<Item = usize> {
+    let width = spacemap
+        .lines()
+        .next()
+        .map(|l| l.chars().count())
+        .expect("size of first line has a max");
+
+    let mut empty = vec![true; width];
+    for line in spacemap.lines() {
+        for (x, ch) in line.chars().enumerate().take(width) {
+            empty[x] &= ch == '.';
+        }
+    }
+    (0..width).filter(move |&x| empty[x])
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
+pub struct Loc {
+    pub x: usize,
+    pub y: usize,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let err = Day11::parse("#.\n..#").unwrap_err();
+        assert_eq!(
+            "line 2, column 3: expected a row of 2 tiles, found \"#\"",
+            err.to_string()
+        );
+
+        let err = Day11::parse("#..\n.#").unwrap_err();
+        assert_eq!((2, 3, ""), (err.line, err.column, err.snippet.as_str()));
+    }
+}
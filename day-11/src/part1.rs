@@ -0,0 +1,98 @@
+use aoc_common::Answer;
+use itertools::Itertools;
+
+use crate::{cols_to_expand, rows_to_expand, Image};
+
+#[allow(dead_code)]
+fn expand_space(spacemap: &str) -> String {
+    let rows_to_expand = rows_to_expand(spacemap).collect_vec();
+    let cols_to_expand = cols_to_expand(spacemap).collect_vec();
+
+    let mut expanded = String::new();
+    spacemap
+        .lines()
+        .enumerate()
+        .fold(&mut expanded, |acc, (y, line)| {
+            let new_line = line
+                .chars()
+                .enumerate()
+                .fold("".to_string(), |mut nline, (x, ch)| {
+                    nline.push(ch);
+                    if cols_to_expand.contains(&x) {
+                        nline.push('.')
+                    }
+                    nline
+                });
+            acc.push_str(&new_line);
+            acc.push('\n');
+            if rows_to_expand.contains(&y) {
+                acc.push_str(&new_line);
+                acc.push('\n');
+            }
+            acc
+        });
+    _ = expanded.pop();
+    expanded
+}
+
+pub fn part1(image: &Image) -> Answer {
+    image.expand(2).sum_of_distances().into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use super::*;
+    use crate::Day11;
+    #[test]
+    #[ignore]
+    fn expand() {
+        let sample = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+        let expected = "....#........
+.........#...
+#............
+.............
+.............
+........#....
+.#...........
+............#
+.............
+.............
+.........#...
+#....#.......";
+        let result = expand_space(sample);
+
+        assert_eq!(
+            expected, result,
+            "\nexpected: \n{} \n actual:\n{} \n",
+            expected, result
+        );
+    }
+
+    #[test]
+    fn example_part1() {
+        let sample = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+        assert_eq!(Answer::Unsigned(374), Day11::solve_part1(sample).unwrap());
+    }
+}
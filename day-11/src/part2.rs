@@ -0,0 +1,50 @@
+use aoc_common::Answer;
+
+use crate::Image;
+
+pub fn part2(image: &Image, inflation_factor: u64) -> Answer {
+    image.expand(inflation_factor).sum_of_distances().into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use super::*;
+    use crate::Day11;
+    // #[ignore]
+    #[test]
+    fn simplified_example() {
+        let sample = "...#......
+.......*..
+*.........
+..........
+......*...
+.*........
+.........*
+..........
+.......#..
+*...*.....";
+
+        assert_eq!(Answer::Unsigned(15), part2(&Day11::parse(sample).unwrap(), 2));
+
+    }
+
+    #[test]
+    // #[ignore]
+    fn example_part2() {
+        let sample = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+        assert_eq!(Answer::Unsigned(1030), part2(&Day11::parse(sample).unwrap(), 10));
+        assert_eq!(Answer::Unsigned(8410), part2(&Day11::parse(sample).unwrap(), 100));
+    }
+}
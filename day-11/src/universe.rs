@@ -0,0 +1,169 @@
+use std::io::{self, Write};
+
+use itertools::Itertools;
+
+/// Expanded galaxy positions, `(x, y)`, in the order they were found. Wide
+/// enough for any inflation factor that fits in a `u64`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Universe {
+    pub galaxies: Vec<(u128, u128)>,
+}
+
+impl Universe {
+    /// Steps between the galaxies at indices `a` and `b`.
+    pub fn distance(&self, a: usize, b: usize) -> u128 {
+        let (a, b) = (self.galaxies[a], self.galaxies[b]);
+        a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+    }
+
+    /// The distance between every pair of galaxies, summed one axis at a
+    /// time so it takes a sort rather than a pass over every pair.
+    pub fn sum_of_distances(&self) -> u128 {
+        axis_sum(self.galaxies.iter().map(|g| g.0).collect())
+            + axis_sum(self.galaxies.iter().map(|g| g.1).collect())
+    }
+
+    /// The `k` galaxies closest to `galaxy` and their distances, nearest
+    /// first. `None` if there is no such galaxy.
+    pub fn nearest(&self, galaxy: usize, k: usize) -> Option<Vec<(usize, u128)>> {
+        (galaxy < self.galaxies.len()).then(|| {
+            (0..self.galaxies.len())
+                .filter(|&other| other != galaxy)
+                .map(|other| (other, self.distance(galaxy, other)))
+                .sorted_by_key(|&(other, distance)| (distance, other))
+                .take(k)
+                .collect()
+        })
+    }
+
+    /// The two galaxies farthest apart and their distance. A Manhattan
+    /// distance is the larger spread of `x + y` or `x - y`, so this only
+    /// needs the extremes of each.
+    pub fn farthest_pair(&self) -> Option<(usize, usize, u128)> {
+        let sum = |&(x, y): &(u128, u128)| (x + y) as i128;
+        let diff = |&(x, y): &(u128, u128)| x as i128 - y as i128;
+        [self.spread(sum), self.spread(diff)]
+            .into_iter()
+            .flatten()
+            .max_by_key(|&(_, _, distance)| distance)
+    }
+
+    fn spread(&self, key: impl Fn(&(u128, u128)) -> i128) -> Option<(usize, usize, u128)> {
+        let (low, high) = self
+            .galaxies
+            .iter()
+            .map(key)
+            .position_minmax()
+            .into_option()?;
+        (low != high).then(|| (low.min(high), low.max(high), self.distance(low, high)))
+    }
+
+    /// Writes the distance between every pair of galaxies as CSV, with a
+    /// header row and column of galaxy indices.
+    pub fn write_csv(&self, mut out: impl Write) -> io::Result<()> {
+        let n = self.galaxies.len();
+        writeln!(out, "galaxy,{}", (0..n).join(","))?;
+        for a in 0..n {
+            writeln!(out, "{a},{}", (0..n).map(|b| self.distance(a, b)).join(","))?;
+        }
+        Ok(())
+    }
+}
+
+/// Once sorted, each coordinate is past each of the `i` before it by its
+/// own value less theirs.
+fn axis_sum(mut coords: Vec<u128>) -> u128 {
+    coords.sort_unstable();
+    let mut before = 0;
+    coords
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| {
+            let total = c * i as u128 - before;
+            before += c;
+            total
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Day11;
+
+    const SAMPLE: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn sums_like_every_pair() {
+        let image = Day11::parse(SAMPLE).unwrap();
+        for factor in [0, 1, 2, 10, 1_000_000] {
+            let universe = image.expand(factor);
+            let n = universe.galaxies.len();
+            let pairwise: u128 = (0..n)
+                .flat_map(|a| (a + 1..n).map(move |b| (a, b)))
+                .map(|(a, b)| universe.distance(a, b))
+                .sum();
+            assert_eq!(pairwise, universe.sum_of_distances(), "factor {factor}");
+        }
+        // Galaxies 5 and 9 in the puzzle's numbering.
+        assert_eq!(9, image.expand(2).distance(4, 8));
+    }
+
+    #[test]
+    fn finds_nearest_and_farthest() {
+        let universe = Day11::parse(SAMPLE).unwrap().expand(2);
+
+        assert_eq!(Some(vec![(3, 5), (0, 6), (5, 9)]), universe.nearest(1, 3));
+        assert_eq!(None, universe.nearest(9, 3));
+        assert_eq!(8, universe.nearest(0, 20).unwrap().len());
+
+        let n = universe.galaxies.len();
+        let farthest = (0..n)
+            .flat_map(|a| (a + 1..n).map(move |b| (a, b)))
+            .map(|(a, b)| universe.distance(a, b))
+            .max();
+        assert_eq!(farthest, universe.farthest_pair().map(|(_, _, d)| d));
+        let (a, b, distance) = universe.farthest_pair().unwrap();
+        assert_eq!(distance, universe.distance(a, b));
+    }
+
+    #[test]
+    fn exports_csv() {
+        let universe = Day11::parse("#.\n.#").unwrap().expand(2);
+        let mut csv = vec![];
+        universe.write_csv(&mut csv).unwrap();
+
+        assert_eq!(
+            "galaxy,0,1\n0,0,2\n1,2,0\n",
+            String::from_utf8(csv).unwrap()
+        );
+        assert_eq!(
+            None,
+            Universe {
+                galaxies: vec![(1, 1)]
+            }
+            .farthest_pair()
+        );
+    }
+
+    #[test]
+    fn expands_without_overflow() {
+        // 82 crossings of an empty row or column, each one factor wide.
+        let image = Day11::parse(SAMPLE).unwrap();
+        assert_eq!(
+            210 + 82 * u64::MAX as u128,
+            image.expand(u64::MAX).sum_of_distances()
+        );
+    }
+}
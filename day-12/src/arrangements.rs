@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+/// Position in the record, index of the current group and length of the run
+/// of damaged springs so far.
+type State = (usize, usize, usize);
+
+const START: State = (0, 0, 0);
+
+/// Counts the ways the `?` springs in `record` can be filled in so the runs of
+/// `#` match `groups`, without building any of them.
+pub fn count_arrangements(record: &str, groups: &[usize]) -> u64 {
+    Counter::new(record, groups).count(START)
+}
+
+/// Lazily yields every arrangement of `record` matching `groups`, as the
+/// record with each `?` replaced. Branches with no arrangements are never
+/// explored, so each one is found in time linear in the record.
+pub struct Arrangements<'a> {
+    counter: Counter<'a>,
+    stack: Vec<(State, Vec<u8>)>,
+}
+
+impl<'a> Arrangements<'a> {
+    pub fn new(record: &'a str, groups: &'a [usize]) -> Self {
+        let mut counter = Counter::new(record, groups);
+        let stack = match counter.count(START) {
+            0 => vec![],
+            _ => vec![(START, Vec::with_capacity(record.len()))],
+        };
+        Self { counter, stack }
+    }
+}
+
+impl Iterator for Arrangements<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((state, filled)) = self.stack.pop() {
+            if state.0 == self.counter.springs.len() {
+                return Some(String::from_utf8(filled).expect("springs are ASCII"));
+            }
+            let steps: Vec<_> = steps(self.counter.springs, self.counter.groups, state).collect();
+            for (spring, next) in steps.into_iter().rev() {
+                if self.counter.count(next) > 0 {
+                    let mut filled = filled.clone();
+                    filled.push(spring);
+                    self.stack.push((next, filled));
+                }
+            }
+        }
+        None
+    }
+}
+
+struct Counter<'a> {
+    springs: &'a [u8],
+    groups: &'a [usize],
+    memo: HashMap<State, u64>,
+}
+
+impl<'a> Counter<'a> {
+    fn new(record: &'a str, groups: &'a [usize]) -> Self {
+        Self {
+            springs: record.as_bytes(),
+            groups,
+            memo: HashMap::new(),
+        }
+    }
+
+    fn count(&mut self, state: State) -> u64 {
+        let (pos, group, run) = state;
+        if pos == self.springs.len() {
+            let done = match run {
+                0 => group == self.groups.len(),
+                _ => group + 1 == self.groups.len() && self.groups[group] == run,
+            };
+            return done as u64;
+        }
+        if let Some(&count) = self.memo.get(&state) {
+            return count;
+        }
+
+        #[cfg(feature = "dhat-ad-hoc")]
+        dhat::ad_hoc_event(1);
+
+        let count = steps(self.springs, self.groups, state)
+            .map(|(_, next)| self.count(next))
+            .sum();
+        self.memo.insert(state, count);
+        count
+    }
+}
+
+/// The springs that can go at the current position and the state each leads
+/// to, damaged first.
+fn steps(springs: &[u8], groups: &[usize], state: State) -> impl Iterator<Item = (u8, State)> {
+    let (pos, group, run) = state;
+    let spring = springs[pos];
+
+    let damaged = (matches!(spring, b'#' | b'?') && group < groups.len() && run < groups[group])
+        .then_some((b'#', (pos + 1, group, run + 1)));
+    let operational = match run {
+        _ if !matches!(spring, b'.' | b'?') => None,
+        0 => Some((b'.', (pos + 1, group, 0))),
+        _ if run == groups[group] => Some((b'.', (pos + 1, group + 1, 0))),
+        _ => None,
+    };
+    damaged.into_iter().chain(operational)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn counts_without_building() {
+        assert_eq!(1, count_arrangements("???.###", &[1, 1, 3]));
+        assert_eq!(10, count_arrangements("?###????????", &[3, 2, 1]));
+        assert_eq!(0, count_arrangements("###", &[2]));
+        assert_eq!(1, count_arrangements("...", &[]));
+        // Far too many candidates to enumerate.
+        assert_eq!(
+            4_694_436_188_839_116_720,
+            count_arrangements(&"?".repeat(100), &[1; 20])
+        );
+    }
+
+    #[test]
+    fn yields_each_arrangement() {
+        let found: Vec<String> = Arrangements::new(".??..??...?##.", &[1, 1, 3]).collect();
+        assert_eq!(
+            vec![
+                ".#...#....###.",
+                ".#....#...###.",
+                "..#..#....###.",
+                "..#...#...###.",
+            ],
+            found
+        );
+        assert_eq!(0, Arrangements::new("###", &[2]).count());
+    }
+
+    #[test]
+    fn agrees_with_count() {
+        for (record, groups) in [
+            ("?#?#?#?#?#?#?#?", vec![1, 3, 1, 6]),
+            ("????.######..#####.", vec![1, 6, 5]),
+            ("?###????????", vec![3, 2, 1]),
+        ] {
+            let count = count_arrangements(record, &groups);
+            assert_eq!(count as usize, Arrangements::new(record, &groups).count());
+        }
+    }
+
+    #[test]
+    fn is_lazy() {
+        let record = "?".repeat(100);
+        let groups = [1; 20];
+        let first: Vec<String> = Arrangements::new(&record, &groups).take(2).collect();
+
+        assert_eq!(2, first.len());
+        assert!(first[0].starts_with("#.#.#."));
+        assert_ne!(first[0], first[1]);
+    }
+}
@@ -1,21 +1,22 @@
-fn main() {
-    let input = include_str!("./input1.txt");
-    let output = part2(input);
-    dbg!(output);
-}
+use aoc_common::{input::exit_with_error, Solution};
+use day_12::Day12;
 
-fn part2(input: &str) -> String {
-    todo!()
-}
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
 
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    #[test]
-    fn example_part2() {
-        let sample = "";
+fn main() {
+    #[cfg(feature = "dhat-heap")]
+    let _profiler = dhat::Profiler::builder()
+        .file_name(aoc_common::profile::report_path(12, "part2-heap.json"))
+        .build();
+    #[cfg(all(feature = "dhat-ad-hoc", not(feature = "dhat-heap")))]
+    let _profiler = dhat::Profiler::builder()
+        .ad_hoc()
+        .file_name(aoc_common::profile::report_path(12, "part2-ad-hoc.json"))
+        .build();
 
-        assert_eq!("", part2(sample));
-    }
+    let input = aoc_common::input::from_args(12);
+    let output = Day12::solve_part2(&input).unwrap_or_else(|e| exit_with_error(e));
+    dbg!(output);
 }
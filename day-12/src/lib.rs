@@ -0,0 +1,148 @@
+use std::str::FromStr;
+
+use aoc_common::{
+    parse::{parse_lines, parse_token},
+    Answer, ParseError, Solution,
+};
+
+pub mod arrangements;
+pub mod part1;
+pub mod part2;
+
+pub use arrangements::{count_arrangements, Arrangements};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Vec<SpringRecord>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::part2(parsed)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SpringRecord {
+    pub record: String,
+    pub allocations: Vec<usize>,
+}
+
+impl From<(&str, Vec<usize>)> for SpringRecord {
+    fn from(value: (&str, Vec<usize>)) -> Self {
+        Self {
+            record: value.0.to_string(),
+            allocations: value.1,
+        }
+    }
+}
+
+impl SpringRecord {
+    /// The record repeated `times`, joined by unknown springs, with its groups
+    /// repeated to match.
+    pub fn unfold(&self, times: usize) -> Self {
+        Self {
+            record: vec![self.record.as_str(); times].join("?"),
+            allocations: self.allocations.repeat(times),
+        }
+    }
+
+    pub fn arrangements(&self) -> u64 {
+        count_arrangements(&self.record, &self.allocations)
+    }
+
+    /// Every concrete arrangement, for checking a single line by hand.
+    pub fn iter_arrangements(&self) -> Arrangements<'_> {
+        Arrangements::new(&self.record, &self.allocations)
+    }
+}
+
+impl FromStr for SpringRecord {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        parse_line(line).map(SpringRecord::from)
+    }
+}
+
+fn parse_line(line: &str) -> Result<(&str, Vec<usize>), ParseError> {
+    let mut it = line.split_whitespace();
+    let records = it
+        .next()
+        .ok_or_else(|| ParseError::at_end(line, "a spring record"))?;
+    if let Some(idx) = records.find(|ch| !matches!(ch, '.' | '#' | '?')) {
+        let end = records[idx..]
+            .char_indices()
+            .nth(1)
+            .map_or(records.len(), |(len, _)| idx + len);
+        return Err(ParseError::at(line, &records[idx..end], "'.', '#' or '?'"));
+    }
+    let nums = it
+        .flat_map(|nums| nums.split(','))
+        .map(|n| parse_token(line, n, "a group size"))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((records, nums))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_record_and_groups() {
+        let expected = SpringRecord {
+            record: "???.###".to_string(),
+            allocations: vec![1, 1, 3],
+        };
+        assert_eq!(Ok(expected), "???.### 1,1,3".parse());
+
+        let err = "??x.### 1,1,3".parse::<SpringRecord>().unwrap_err();
+        assert_eq!((3, "x"), (err.column, err.snippet.as_str()));
+
+        let err = "???.### 1,,3".parse::<SpringRecord>().unwrap_err();
+        assert_eq!(
+            "line 1, column 11: expected a group size, found end of line",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn part1_sums_the_listed_arrangements() {
+        let sample = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+        let records = Day12::parse(sample).unwrap();
+        let listed = records
+            .iter()
+            .map(|r| r.iter_arrangements().count() as u64)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 4, 1, 1, 4, 10], listed);
+        assert_eq!(
+            Answer::from(listed.iter().sum::<u64>()),
+            Day12::part1(&records)
+        );
+        assert_eq!(0, count_arrangements("###", &[2]));
+        assert_eq!(1, count_arrangements("...", &[]));
+    }
+
+    #[test]
+    fn unfolds_five_times() {
+        let record: SpringRecord = ".# 1".parse().unwrap();
+        let expected = SpringRecord {
+            record: ".#?.#?.#?.#?.#".to_string(),
+            allocations: vec![1; 5],
+        };
+        assert_eq!(expected, record.unfold(5));
+    }
+}
@@ -0,0 +1,72 @@
+use aoc_common::Answer;
+
+use crate::SpringRecord;
+
+pub fn part1(records: &[SpringRecord]) -> Answer {
+    records
+        .iter()
+        .map(SpringRecord::arrangements)
+        .sum::<u64>()
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day12;
+
+    #[test]
+    fn first_line() {
+        let sample = "???.### 1,1,3";
+
+        assert_eq!(Answer::Unsigned(1), Day12::solve_part1(sample).unwrap());
+    }
+
+    #[test]
+    fn second_line() {
+        let sample = ".??..??...?##. 1,1,3";
+
+        assert_eq!(Answer::Unsigned(4), Day12::solve_part1(sample).unwrap());
+    }
+
+    #[test]
+    fn third() {
+        let sample = "?#?#?#?#?#?#?#? 1,3,1,6";
+
+        assert_eq!(Answer::Unsigned(1), Day12::solve_part1(sample).unwrap());
+    }
+
+    #[test]
+    fn fourth() {
+        let sample = "????.#...#... 4,1,1";
+
+        assert_eq!(Answer::Unsigned(1), Day12::solve_part1(sample).unwrap());
+    }
+
+    #[test]
+    fn fifth() {
+        let sample = "????.######..#####. 1,6,5";
+
+        assert_eq!(Answer::Unsigned(4), Day12::solve_part1(sample).unwrap());
+    }
+
+    #[test]
+    fn sixth() {
+        let sample = "?###???????? 3,2,1";
+
+        assert_eq!(Answer::Unsigned(10), Day12::solve_part1(sample).unwrap());
+    }
+
+    #[test]
+    fn example_part1() {
+        let sample = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+        assert_eq!(Answer::Unsigned(21), Day12::solve_part1(sample).unwrap());
+    }
+}
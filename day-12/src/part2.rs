@@ -0,0 +1,48 @@
+use aoc_common::Answer;
+
+use crate::SpringRecord;
+
+pub fn part2(records: &[SpringRecord]) -> Answer {
+    records
+        .iter()
+        .map(|r| r.unfold(5).arrangements())
+        .sum::<u64>()
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day12;
+
+    #[test]
+    fn unfolded_lines() {
+        let counts = [
+            ("???.### 1,1,3", 1),
+            (".??..??...?##. 1,1,3", 16384),
+            ("?#?#?#?#?#?#?#? 1,3,1,6", 1),
+            ("????.#...#... 4,1,1", 16),
+            ("????.######..#####. 1,6,5", 2500),
+            ("?###???????? 3,2,1", 506250),
+        ];
+        for (line, count) in counts {
+            assert_eq!(Answer::Unsigned(count), Day12::solve_part2(line).unwrap());
+        }
+    }
+
+    #[test]
+    fn example_part2() {
+        let sample = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+        assert_eq!(
+            Answer::Unsigned(525152),
+            Day12::solve_part2(sample).unwrap()
+        );
+    }
+}
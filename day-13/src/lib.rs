@@ -0,0 +1,52 @@
+use aoc_common::{Answer, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+pub mod pattern;
+
+use pattern::Pattern;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut first_line = 1;
+        input
+            .split("\n\n")
+            .map(|pattern| {
+                let pattern = pattern
+                    .parse::<Pattern>()
+                    .map_err(|e| e.on_line(first_line))?;
+                first_line += pattern.height() + 1;
+                Ok(pattern)
+            })
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::part2(parsed)
+    }
+}
+
+/// Summarizes a pattern: columns left of a vertical mirror plus 100 times
+/// the rows above a horizontal one.
+pub fn score(vert: usize, horz: usize) -> usize {
+    vert + (horz * 100)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reports_position_within_input() {
+        let err = Day13::parse("#.\n.#\n\n##\n#x").unwrap_err();
+        assert_eq!((5, 2, "x"), (err.line, err.column, err.snippet.as_str()));
+    }
+}
@@ -0,0 +1,39 @@
+use aoc_common::Answer;
+
+use crate::pattern::Pattern;
+
+pub fn part1(patterns: &[Pattern]) -> Answer {
+    patterns
+        .iter()
+        .map(|p| p.summarize(0))
+        .sum::<usize>()
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day13;
+
+    #[test]
+    fn example_part1() {
+        let sample = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+        assert_eq!(Answer::Unsigned(405), Day13::solve_part1(sample).unwrap());
+    }
+}
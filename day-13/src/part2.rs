@@ -0,0 +1,39 @@
+use aoc_common::Answer;
+
+use crate::pattern::Pattern;
+
+pub fn part2(patterns: &[Pattern]) -> Answer {
+    patterns
+        .iter()
+        .map(|p| p.summarize(1))
+        .sum::<usize>()
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day13;
+
+    #[test]
+    fn example_part2() {
+        let sample = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+        assert_eq!(Answer::Unsigned(400), Day13::solve_part2(sample).unwrap());
+    }
+}
@@ -0,0 +1,210 @@
+use std::str::FromStr;
+
+use aoc_common::{grid::parse_tiles, ParseError};
+
+use crate::score;
+
+/// Which way a mirror runs: between two rows or between two columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+/// A line that reflects the pattern once `smudges` are repaired.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mirror {
+    pub axis: Axis,
+    /// Rows above or columns left of the line.
+    pub position: usize,
+    /// The `(x, y)` of each smudge on the top or left side. Flipping it, or
+    /// its reflection, makes the two sides match.
+    pub smudges: Vec<(usize, usize)>,
+}
+
+impl Mirror {
+    pub fn score(&self) -> usize {
+        match self.axis {
+            Axis::Horizontal => score(0, self.position),
+            Axis::Vertical => score(self.position, 0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    grid: Vec<Vec<char>>,
+}
+
+impl FromStr for Pattern {
+    type Err = ParseError;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        let grid = parse_tiles(pattern, ".#")?;
+        if grid.is_empty() {
+            return Err(ParseError::at_end(pattern, "a pattern of '.' and '#'"));
+        }
+        Ok(Pattern { grid })
+    }
+}
+
+impl Pattern {
+    pub fn height(&self) -> usize {
+        self.grid.len()
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid[0].len()
+    }
+
+    /// Every line, horizontal ones first, that reflects the pattern after
+    /// repairing exactly `smudges` tiles.
+    pub fn mirrors(&self, smudges: usize) -> Vec<Mirror> {
+        [Axis::Horizontal, Axis::Vertical]
+            .into_iter()
+            .flat_map(|axis| self.mirrors_along(axis, smudges))
+            .collect()
+    }
+
+    /// The summary of every mirror needing exactly `smudges` repairs.
+    pub fn summarize(&self, smudges: usize) -> usize {
+        self.mirrors(smudges).iter().map(Mirror::score).sum()
+    }
+
+    fn mirrors_along(&self, axis: Axis, budget: usize) -> impl Iterator<Item = Mirror> + '_ {
+        let (lines, across) = match axis {
+            Axis::Horizontal => (self.height(), self.width()),
+            Axis::Vertical => (self.width(), self.height()),
+        };
+        let tile = move |line: usize, i: usize| match axis {
+            Axis::Horizontal => (i, line),
+            Axis::Vertical => (line, i),
+        };
+
+        (1..lines).filter_map(move |position| {
+            let mut smudges = vec![];
+            for (before, after) in (0..position).rev().zip(position..lines) {
+                for i in 0..across {
+                    let (a, b) = (tile(before, i), tile(after, i));
+                    if self.grid[a.1][a.0] != self.grid[b.1][b.0] {
+                        if smudges.len() == budget {
+                            return None;
+                        }
+                        smudges.push(a);
+                    }
+                }
+            }
+            (smudges.len() == budget).then_some(Mirror {
+                axis,
+                position,
+                smudges,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const VERTICAL: &str = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.";
+
+    const HORIZONTAL: &str = "#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+    #[test]
+    fn finds_clean_mirrors() {
+        let vertical: Pattern = VERTICAL.parse().unwrap();
+        assert_eq!(
+            vec![Mirror {
+                axis: Axis::Vertical,
+                position: 5,
+                smudges: vec![]
+            }],
+            vertical.mirrors(0)
+        );
+
+        let horizontal: Pattern = HORIZONTAL.parse().unwrap();
+        assert_eq!(400, horizontal.summarize(0));
+    }
+
+    #[test]
+    fn repairs_smudges() {
+        let vertical: Pattern = VERTICAL.parse().unwrap();
+        assert_eq!(
+            vec![Mirror {
+                axis: Axis::Horizontal,
+                position: 3,
+                smudges: vec![(0, 0)]
+            }],
+            vertical.mirrors(1)
+        );
+
+        let horizontal: Pattern = HORIZONTAL.parse().unwrap();
+        assert_eq!(
+            vec![Mirror {
+                axis: Axis::Horizontal,
+                position: 1,
+                smudges: vec![(4, 0)]
+            }],
+            horizontal.mirrors(1)
+        );
+    }
+
+    #[test]
+    fn finds_vertical_mirrors_with_smudges() {
+        let pattern: Pattern = "#.##.#\n..##.#\n##..#.".parse().unwrap();
+        assert_eq!(
+            vec![Mirror {
+                axis: Axis::Vertical,
+                position: 3,
+                smudges: vec![(0, 1), (0, 2)]
+            }],
+            pattern.mirrors(2)
+        );
+        assert_eq!(3, pattern.summarize(2));
+    }
+
+    #[test]
+    fn keeps_clean_and_smudged_mirrors_apart() {
+        // Part 2 used to search rows only, accepting the clean line at 10.
+        let pattern: Pattern = "...#..##.####.#
+##..##.###..###
+#.#............
+#...#...#..#.#.
+.#..###.#.##.#.
+###.###.##..##.
+.....##........
+##..#....#..#..
+#....#...#..#..
+#..##.#.#.##.#.
+#..##.#.#.##.#.
+#....#...#..#..
+##..#....#..#..
+.....##........
+###.###.##..##."
+            .parse()
+            .unwrap();
+
+        assert_eq!(1000, pattern.summarize(0));
+        assert_eq!(
+            vec![Mirror {
+                axis: Axis::Vertical,
+                position: 11,
+                smudges: vec![(10, 3)]
+            }],
+            pattern.mirrors(1)
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use aoc_common::Answer;
+
+use crate::Map;
+
+pub fn part1(grid: &[Vec<char>]) -> Answer {
+    let mut map = Map::new(grid.to_vec());
+    map.roll_north();
+    map.score().into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day14;
+
+    #[test]
+    fn example_part1() {
+        let sample = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+        assert_eq!(Answer::Unsigned(136), Day14::solve_part1(sample).unwrap());
+    }
+}
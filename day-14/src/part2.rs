@@ -0,0 +1,30 @@
+use aoc_common::Answer;
+
+use crate::Map;
+
+pub fn part2(grid: &[Vec<char>]) -> Answer {
+    let mut map = Map::new(grid.to_vec());
+    map.spin(1_000_000_000).into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day14;
+
+    #[test]
+    fn example_part2() {
+        let sample = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+        assert_eq!(Answer::Unsigned(64), Day14::solve_part2(sample).unwrap());
+    }
+}
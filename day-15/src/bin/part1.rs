@@ -1,49 +1,22 @@
+use aoc_common::{input::exit_with_error, Solution};
+use day_15::Day15;
+
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
 static ALLOC: dhat::Alloc = dhat::Alloc;
 
 fn main() {
     #[cfg(feature = "dhat-heap")]
-    let _profiler = dhat::Profiler::new_heap();
-
-    let input = include_str!("./input1.txt");
-    let output = part1(input);
+    let _profiler = dhat::Profiler::builder()
+        .file_name(aoc_common::profile::report_path(15, "part1-heap.json"))
+        .build();
+    #[cfg(all(feature = "dhat-ad-hoc", not(feature = "dhat-heap")))]
+    let _profiler = dhat::Profiler::builder()
+        .ad_hoc()
+        .file_name(aoc_common::profile::report_path(15, "part1-ad-hoc.json"))
+        .build();
+
+    let input = aoc_common::input::from_args(15);
+    let output = Day15::solve_part1(&input).unwrap_or_else(|e| exit_with_error(e));
     dbg!(output);
 }
-
-fn part1(input: &str) -> String {
-    input.trim().split(',').map(holiday_helper_hash).sum::<u32>().to_string()
-}
-
-fn holiday_helper_hash(fragment: &str) -> u32 {
-    fragment.chars().fold(0, |mut acc, ch| {
-        acc += ch as u32;
-        acc *= 17;
-        acc %= 256;
-        acc
-    })
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    #[test]
-    fn hash_algo_breakdown() {
-        let sample = "HASH";
-
-        assert_eq!(52, holiday_helper_hash(sample));
-    }
-
-    #[test]
-    fn hash_algo() {
-        let sample = "rn=1";
-
-        assert_eq!(30, holiday_helper_hash(sample));
-    }
-    #[test]
-    fn example_part1() {
-        let sample = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-
-        assert_eq!("1320", part1(sample));
-    }
-}
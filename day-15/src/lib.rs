@@ -0,0 +1,126 @@
+use aoc_common::{parse::parse_token, Answer, ParseError, Solution};
+use Instruction::*;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input
+            .trim()
+            .split(',')
+            .map(|step| {
+                Ok(Step {
+                    raw: step.to_string(),
+                    instruction: Instruction::parse(input, step)?,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::part2(parsed)
+    }
+}
+
+/// A step of the initialization sequence: part 1 hashes it as written,
+/// part 2 follows its instruction.
+#[derive(Debug, PartialEq)]
+pub struct Step {
+    pub raw: String,
+    pub instruction: Instruction,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Lens {
+    pub label: String,
+    pub focal_length: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Instruction {
+    Remove(String),
+    Insert(Lens),
+}
+
+impl Instruction {
+    pub fn parse(source: &str, step: &str) -> Result<Self, ParseError> {
+        match step.split_once('=') {
+            Some((label, focal_length)) => {
+                let focal_length = parse_token(source, focal_length, "a focal length")?;
+                Ok(Insert(Lens {
+                    label: label.to_string(),
+                    focal_length,
+                }))
+            }
+            None => step
+                .strip_suffix('-')
+                .map(|label| Remove(label.to_string()))
+                .ok_or_else(|| ParseError::at(source, &step[step.len()..], "'=' or '-'")),
+        }
+    }
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Instruction::parse(value, value)
+    }
+}
+
+pub fn holiday_helper_hash(fragment: &str) -> u32 {
+    fragment.chars().fold(0, |mut acc, ch| {
+        acc += ch as u32;
+        acc *= 17;
+        acc %= 256;
+        acc
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn hash_algo_breakdown() {
+        let sample = "HASH";
+
+        assert_eq!(52, holiday_helper_hash(sample));
+    }
+
+    #[test]
+    fn hash_algo() {
+        let sample = "rn=1";
+
+        assert_eq!(30, holiday_helper_hash(sample));
+    }
+
+    #[test]
+    fn parses_instruction() {
+        let sample = "rn=1";
+        let expected = Instruction::Insert(Lens {
+            label: "rn".to_string(),
+            focal_length: 1,
+        });
+
+        assert_eq!(Ok(expected), Instruction::try_from(sample));
+    }
+
+    #[test]
+    fn reports_bad_step() {
+        let err = Day15::parse("rn=1,cm+,qp=3").unwrap_err();
+        assert_eq!(
+            "line 1, column 9: expected '=' or '-', found end of line",
+            err.to_string()
+        );
+    }
+}
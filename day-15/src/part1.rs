@@ -0,0 +1,25 @@
+use aoc_common::Answer;
+
+use crate::{holiday_helper_hash, Step};
+
+pub fn part1(steps: &[Step]) -> Answer {
+    steps
+        .iter()
+        .map(|s| holiday_helper_hash(&s.raw))
+        .sum::<u32>()
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day15;
+
+    #[test]
+    fn example_part1() {
+        let sample = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+        assert_eq!(Answer::Unsigned(1320), Day15::solve_part1(sample).unwrap());
+    }
+}
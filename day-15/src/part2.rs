@@ -0,0 +1,51 @@
+use aoc_common::Answer;
+
+use crate::{holiday_helper_hash, Instruction, Lens, Step};
+
+pub fn part2(steps: &[Step]) -> Answer {
+    let mut boxes: [Vec<&Lens>; 256] = core::array::from_fn(|_| vec![]);
+    steps.iter().map(|s| &s.instruction).for_each(|inst| {
+        match inst {
+            Instruction::Remove(label) => {
+                let box_idx = holiday_helper_hash(label) as usize;
+                if let Some(lens_idx) = boxes[box_idx].iter().position(|l| &l.label == label) {
+                    boxes[box_idx].remove(lens_idx);
+                }
+            },
+            Instruction::Insert(lens) => {
+                let box_idx = holiday_helper_hash(&lens.label) as usize;
+                if let Some(prev_lens) = boxes[box_idx].iter_mut().find(|l| l.label == lens.label) {
+                    *prev_lens = lens;
+                } else {
+                    boxes[box_idx].push(lens);
+                }
+            }
+        }
+    });
+    // dbg!(&boxes);
+    power(&boxes).into()
+}
+
+fn power(boxes: &[Vec<&Lens>]) -> usize {
+    boxes.iter().enumerate().map(|(idx, lenses)| {
+        let box_power = idx + 1;
+        lenses.iter().enumerate().map(|(lens_idx, lens)| {
+            let lens_position = lens_idx + 1;
+            box_power * lens_position * lens.focal_length
+        }).sum::<usize>()
+    }).sum::<usize>()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day15;
+
+    #[test]
+    fn example_part2() {
+        let sample = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+        assert_eq!(Answer::Unsigned(145), Day15::solve_part2(sample).unwrap());
+    }
+}
@@ -0,0 +1,210 @@
+use std::{collections::HashSet, ops::Add};
+
+use aoc_common::{grid::parse_tiles, Answer, Direction, ParseError, Solution};
+use itertools::Itertools;
+use Direction::*;
+use Tile::*;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_tiles(input, r"./\|-")
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::part2(parsed)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+    pub dir: Direction,
+}
+
+impl Point {
+    fn in_bounds(&self, maxx: usize, maxy: usize) -> bool {
+        (0..maxx).contains(&self.x) && (0..maxy).contains(&self.y)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Self;
+
+    fn add(self, other: Direction) -> Self {
+        let delta = other.delta();
+        let x = self.x as i64 + delta.0 as i64;
+        let y = self.y as i64 + delta.1 as i64;
+        Self {
+            x: x as usize,
+            y: y as usize,
+            dir: other,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Beam {
+    pub loc: Point,
+    pub dir: Direction,
+    pub maxx: usize,
+    pub maxy: usize,
+}
+
+#[derive(Debug)]
+pub enum Tile {
+    Empty,
+    UpCorner,
+    DownCorner,
+    SplitVert,
+    SplitHoriz,
+}
+
+impl From<&char> for Tile {
+    fn from(value: &char) -> Self {
+        match value {
+            '.' => Empty,
+            '/' => UpCorner,
+            '\\' => DownCorner,
+            '|' => SplitVert,
+            '-' => SplitHoriz,
+            _ => unreachable!("There are no other characters in the grid."),
+        }
+    }
+}
+
+impl Beam {
+    fn advance(&mut self, grid: &[Vec<char>], used: &mut HashSet<Point>) -> Option<Tile> {
+        used.insert(self.loc);
+        let new_loc = self.loc + self.dir;
+        if new_loc.in_bounds(self.maxx, self.maxy) && !used.contains(&new_loc) {
+            self.loc = new_loc;
+        } else {
+            return None;
+        }
+        Some(Tile::from(&grid[self.loc.y][self.loc.x]))
+    }
+
+    fn bounce(&mut self, tile: Tile) {
+        match (self.dir, tile) {
+            (East, UpCorner) => self.dir = North,
+            (South, UpCorner) => self.dir = West,
+            (North, UpCorner) => self.dir = East,
+            (West, UpCorner) => self.dir = South,
+            (East, DownCorner) => self.dir = South,
+            (South, DownCorner) => self.dir = East,
+            (North, DownCorner) => self.dir = West,
+            (West, DownCorner) => self.dir = North,
+            (_, _) => panic!("Doesn't bounce at beam splitters."),
+        }
+    }
+
+    fn split_beam(&mut self, tile: Tile) -> Option<Self> {
+        match (self.dir, tile) {
+            (East, SplitVert) => {
+                self.dir = North;
+                Some(Self {
+                    dir: South,
+                    ..*self
+                })
+            }
+            (West, SplitVert) => {
+                self.dir = North;
+                Some(Self {
+                    dir: South,
+                    ..*self
+                })
+            }
+            (North, SplitHoriz) => {
+                self.dir = East;
+                Some(Self { dir: West, ..*self })
+            }
+            (South, SplitHoriz) => {
+                self.dir = East;
+                Some(Self { dir: West, ..*self })
+            }
+            (North, SplitVert) => None,
+            (South, SplitVert) => None,
+            (East, SplitHoriz) => None,
+            (West, SplitHoriz) => None,
+            n @ (_, _) => panic!("Should not try to split {:?}.", n),
+        }
+    }
+}
+
+/// Number of tiles energized by a beam entering the grid at `starting_beam`.
+pub fn energy_count(starting_beam: Beam, grid: &[Vec<char>]) -> usize {
+    let mut processing = vec![starting_beam];
+    let mut used: HashSet<Point> = HashSet::new();
+
+    while let Some(mut beam) = processing.pop() {
+        #[cfg(feature = "dhat-ad-hoc")]
+        dhat::ad_hoc_event(1);
+        match beam.advance(grid, &mut used) {
+            Some(Empty) => processing.push(beam),
+            Some(UpCorner) => {
+                beam.bounce(UpCorner);
+                processing.push(beam);
+            }
+            Some(DownCorner) => {
+                beam.bounce(DownCorner);
+                processing.push(beam);
+            }
+            Some(SplitVert) => {
+                if let Some(other_beam) = beam.split_beam(SplitVert) {
+                    processing.push(other_beam);
+                }
+                processing.push(beam);
+            }
+            Some(SplitHoriz) => {
+                if let Some(other_beam) = beam.split_beam(SplitHoriz) {
+                    processing.push(other_beam);
+                }
+                processing.push(beam);
+            }
+            None => {}
+        }
+    }
+
+    used.iter().unique_by(|p| (p.x, p.y)).count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn point_equality_in_set() {
+        let mut used = HashSet::<Point>::new();
+        let p1 = Point {
+            x: 5,
+            y: 10,
+            dir: North,
+        };
+        let p2 = Point {
+            x: 5,
+            y: 10,
+            dir: North,
+        };
+        let p3 = Point {
+            x: 5,
+            y: 10,
+            dir: South,
+        };
+
+        assert!(used.insert(p1));
+        assert!(!used.insert(p2));
+        assert!(used.insert(p3));
+    }
+}
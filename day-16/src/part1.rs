@@ -0,0 +1,41 @@
+use aoc_common::{Answer, Direction::East};
+
+use crate::{energy_count, Beam, Point};
+
+pub fn part1(grid: &[Vec<char>]) -> Answer {
+    let starting_beam = Beam {
+        loc: Point {
+            x: 0,
+            y: 0,
+            dir: East,
+        },
+        dir: East,
+        maxy: grid.len(),
+        maxx: grid[0].len(),
+    };
+
+    energy_count(starting_beam, grid).into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day16;
+
+    #[test]
+    fn example_part1() {
+        let sample = r#".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|...."#;
+
+        assert_eq!(Answer::Unsigned(46), Day16::solve_part1(sample).unwrap());
+    }
+}
@@ -0,0 +1,60 @@
+use aoc_common::{Answer, Direction::*};
+
+use crate::{energy_count, Beam, Point};
+
+pub fn part2(grid: &[Vec<char>]) -> Answer {
+    let north_edge = (0..grid[0].len()).map(|x| Point {
+        x,
+        y: 0,
+        dir: South,
+    });
+    let west_edge = (0..grid.len()).map(|y| Point {
+        x: 0,
+        y,
+        dir: East,
+    });
+    let east_edge = (0..grid.len()).map(|y| Point {
+        x: grid[0].len() - 1,
+        y,
+        dir: West,
+    });
+    let south_edge = (0..grid[0].len()).map(|x| Point {
+        x,
+        y: grid.len() - 1,
+        dir: North,
+    });
+
+    let it = north_edge
+        .chain(east_edge)
+        .chain(south_edge)
+        .chain(west_edge);
+    it.map(|p| Beam {
+        loc: p,
+        dir: p.dir,
+        maxy: grid.len(),
+        maxx: grid[0].len(),
+    }).map(|b| energy_count(b, grid)).max().unwrap_or(0).into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day16;
+
+    #[test]
+    fn example_part2() {
+        let sample = r#".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|...."#;
+
+        assert_eq!(Answer::Unsigned(51), Day16::solve_part2(sample).unwrap());
+    }
+}
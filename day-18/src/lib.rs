@@ -0,0 +1,223 @@
+use std::str::FromStr;
+
+use aoc_common::{
+    parse::{parse_lines, parse_token},
+    polygon, Answer, Direction, ParseError, Solution,
+};
+use itertools::Itertools;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::part1(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::part2(parsed)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Instruction {
+    pub dir: Direction,
+    pub steps: u32,
+    pub color: String,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut it = line.split_whitespace();
+        let mut next = |expected| it.next().ok_or_else(|| ParseError::at_end(line, expected));
+
+        let dir = next("a direction")?;
+        let dir = parse_direction(dir).ok_or_else(|| ParseError::at(line, dir, "U, D, L or R"))?;
+        let steps = parse_token(line, next("a number of steps")?, "a number of steps")?;
+
+        let color = next("a hex color")?;
+        let hex = color
+            .strip_prefix("(#")
+            .and_then(|s| s.strip_suffix(')'))
+            .filter(|hex| hex.len() == 6 && hex.chars().all(|ch| ch.is_ascii_hexdigit()))
+            .ok_or_else(|| ParseError::at(line, color, "a hex color like (#70c710)"))?;
+        if !matches!(hex.chars().last(), Some('0'..='3')) {
+            return Err(ParseError::at(line, &hex[5..], "a direction digit 0-3"));
+        }
+
+        Ok(Self {
+            dir,
+            steps,
+            color: color[1..color.len() - 1].to_string(),
+        })
+    }
+}
+
+impl Instruction {
+    /// The real instruction hidden in the color: five hex digits of steps
+    /// followed by a direction digit.
+    pub fn decoded(&self) -> Self {
+        let mut color = self
+            .color
+            .strip_prefix('#')
+            .expect("color is checked by from_str")
+            .to_string();
+
+        let dir = color
+            .pop()
+            .and_then(|d| d.to_digit(10))
+            .and_then(direction_from_digit)
+            .expect("color is checked by from_str");
+
+        // dbg!(&color);
+        let steps: u32 =
+            u32::from_str_radix(color.as_str(), 16).expect("color is checked by from_str");
+
+        Self { dir, steps, color }
+    }
+}
+
+fn direction_from_digit(value: u32) -> Option<Direction> {
+    match value {
+        0 => Some(Direction::East),
+        1 => Some(Direction::South),
+        2 => Some(Direction::West),
+        3 => Some(Direction::North),
+        _ => None,
+    }
+}
+
+fn parse_direction(value: &str) -> Option<Direction> {
+    match value {
+        "U" => Some(Direction::North),
+        "D" => Some(Direction::South),
+        "L" => Some(Direction::West),
+        "R" => Some(Direction::East),
+        _ => None,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Vertex {
+    pub x: i64,
+    pub y: i64,
+    pub color: String,
+    pub dir: Option<Direction>,
+}
+
+impl Vertex {
+    pub fn follow(&self, instruction: &Instruction) -> Self {
+        let (dx, dy) = instruction.dir.delta();
+        let n = instruction.steps as i64;
+
+        Self {
+            x: self.x + dx as i64 * n,
+            y: self.y + dy as i64 * n,
+            color: instruction.color.clone(),
+            dir: Some(instruction.dir),
+        }
+    }
+}
+
+/// Cubic meters of lava the lagoon dug by `instructions` holds: the cubes
+/// inside the trench plus the trench itself.
+pub fn lagoon_area(instructions: impl IntoIterator<Item = Instruction>) -> i64 {
+    let start = Vertex {
+        x: 0,
+        y: 0,
+        color: "#000000".into(),
+        dir: None,
+    };
+    let vertices = instructions.into_iter().fold(vec![start], |mut acc, inst| {
+        if let Some(prev) = acc.last() {
+            acc.push(prev.follow(&inst));
+        }
+        acc
+    });
+
+    let corners = vertices.iter().map(|v| (v.x, v.y)).collect_vec();
+    polygon::interior_points(&corners) + polygon::boundary_points(&corners)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    #[ignore]
+    fn instructions() {
+        let sample = "R 6 (#70c710)";
+        let expected_instruction = Instruction {
+            dir: Direction::East,
+            steps: 6,
+            color: String::from("#70c710"),
+        };
+        assert_eq!(Ok(expected_instruction), sample.parse());
+    }
+
+    #[test]
+    fn reports_bad_instruction() {
+        let err = Day18::parse("R 6 (#70c710)\nX 5 (#0dc571)").unwrap_err();
+        assert_eq!(
+            "line 2, column 1: expected U, D, L or R, found \"X\"",
+            err.to_string()
+        );
+
+        let err = "R 6 (#70c714)".parse::<Instruction>().unwrap_err();
+        assert_eq!((12, "4"), (err.column, err.snippet.as_str()));
+    }
+
+    #[test]
+    fn shoelace_area() {
+        let corners = [(1, 6), (3, 1), (7, 2), (4, 4), (8, 5)];
+        assert_eq!(16, polygon::double_area(&corners) / 2);
+    }
+
+    #[test]
+    fn part1_area() {
+        let sample = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+        let start = Vertex {
+            x: 0,
+            y: 0,
+            color: "#000000".into(),
+            dir: None,
+        };
+        let vertices = sample
+            .lines()
+            .map(|l| l.parse::<Instruction>().unwrap())
+            .fold(vec![start.clone()], |mut acc, inst| {
+                if let Some(prev) = acc.last() {
+                    acc.push(prev.follow(&inst));
+                }
+                acc
+            });
+
+        let corners = vertices.iter().map(|v| (v.x, v.y)).collect_vec();
+        let perimeter = polygon::boundary_points(&corners);
+        assert_eq!(38, perimeter);
+        assert_eq!(62, polygon::interior_points(&corners) + perimeter);
+    }
+}
@@ -0,0 +1,34 @@
+use aoc_common::Answer;
+
+use crate::{lagoon_area, Instruction};
+
+pub fn part1(instructions: &[Instruction]) -> Answer {
+    lagoon_area(instructions.iter().cloned()).into()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::{Answer, Solution};
+
+    use crate::Day18;
+
+    #[test]
+    fn example_part1() {
+        let sample = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+        assert_eq!(Answer::Signed(62), Day18::solve_part1(sample).unwrap());
+    }
+}